@@ -1,11 +1,12 @@
 use crate::syn_utils::*;
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use syn::{
-    Attribute, Data, DeriveInput, Field, Fields, GenericArgument, Ident, LitStr, PathArguments,
-    Result, Token, Type,
+    Attribute, Data, DataEnum, DeriveInput, Expr, Field, Fields, GenericArgument, Ident, LitStr,
+    Path, PathArguments, Result, Token, Type,
     ext::IdentExt,
+    parenthesized,
     parse::{Parse, ParseStream},
     parse_quote,
     punctuated::Punctuated,
@@ -13,30 +14,187 @@ use syn::{
 };
 
 pub fn derive_struct_meta(input: DeriveInput) -> Result<TokenStream> {
-    if let Data::Struct(data) = &input.data {
-        let mut args = ArgsForStruct::default();
-        for attr in &input.attrs {
-            if attr.path().is_ident("struct_meta") {
-                args.parse_from_attr(attr)?;
-            }
+    let mut args = ArgsForStruct::default();
+    for attr in &input.attrs {
+        if attr.path().is_ident("struct_meta") {
+            args.parse_from_attr(attr)?;
+        }
+    }
+    let (body, flatten, to_tokens, schema) = match &input.data {
+        Data::Struct(data) => {
+            let ps = Params::from_fields(&data.fields, &args)?;
+            args.check_groups_used(&ps.group_names())?;
+            let to_tokens = ps.build_to_tokens(&quote!(Self), None);
+            (
+                ps.build(&quote!(Self)),
+                ps.build_flatten(),
+                quote!(match self { #to_tokens }),
+                ps.build_schema(),
+            )
         }
-        let ps = Params::from_fields(&data.fields, &args)?;
-        let body = ps.build();
-        impl_trait_result(
+        Data::Enum(data) => {
+            let (body, to_tokens, schema) = build_enum(data, &args)?;
+            (body, None, to_tokens, schema)
+        }
+        Data::Union(_) => {
+            let span = input.span();
+            bail!(
+                span,
+                "`#[derive(StructMeta)]` supports only struct and enum."
+            )
+        }
+    };
+    let mut ts = impl_trait(
+        &input,
+        &parse_quote!(::structmeta::helpers::exports::syn::parse::Parse),
+        &[],
+        quote! {
+            fn parse(input: ::structmeta::helpers::exports::syn::parse::ParseStream<'_>) -> ::structmeta::helpers::exports::syn::Result<Self> {
+                #body
+            }
+        },
+    );
+    ts.extend(impl_trait(
+        &input,
+        &parse_quote!(::structmeta::ArgsSchema),
+        &[],
+        quote! {
+            const SCHEMA: &'static ::structmeta::Schema = &#schema;
+        },
+    ));
+    if let Some(flatten) = flatten {
+        ts.extend(impl_trait(
             &input,
-            &parse_quote!(::structmeta::helpers::exports::syn::parse::Parse),
+            &parse_quote!(::structmeta::helpers::Flatten),
+            &[],
+            flatten,
+        ));
+    }
+    if args.to_tokens {
+        let name_value_separator = &args.name_value_separators()[0];
+        let separator = args.separator();
+        ts.extend(impl_trait(
+            &input,
+            &parse_quote!(::structmeta::helpers::exports::quote::ToTokens),
             &[],
             quote! {
-                fn parse(input: ::structmeta::helpers::exports::syn::parse::ParseStream<'_>) -> ::structmeta::helpers::exports::syn::Result<Self> {
-                    #body
+                fn to_tokens(&self, tokens: &mut ::structmeta::helpers::exports::proc_macro2::TokenStream) {
+                    let mut args = ::structmeta::helpers::ArgTokens::new(#name_value_separator, #separator);
+                    #to_tokens
+                    args.finish(tokens);
                 }
             },
-            args.dump,
-        )
-    } else {
-        let span = input.span();
-        bail!(span, "`#[derive(StructMeta)]` supports only struct.")
+        ));
+    }
+    if !args.attrs.is_empty() {
+        ts.extend(build_from_attrs(&input, &args));
+    }
+    if args.dump {
+        panic!("macro result: \n{ts}");
+    }
+    Ok(ts)
+}
+fn build_from_attrs(input: &DeriveInput, args: &ArgsForStruct) -> TokenStream {
+    let vis = &input.vis;
+    let ty = &input.ident;
+    let (impl_g, ty_g, where_clause) = input.generics.split_for_impl();
+    let paths = &args.attrs;
+    let separator = args.separator();
+    let doc = format!(
+        " Parse the arguments of all `#[{}(...)]` attributes as one argument list.",
+        paths[0]
+    );
+    let doc_required = format!(
+        " Same as `from_attrs`, but an error is returned if there is no `#[{}(...)]` attribute.",
+        paths[0]
+    );
+    quote! {
+        #[automatically_derived]
+        impl #impl_g #ty #ty_g #where_clause {
+            #[doc = #doc]
+            #vis fn from_attrs(
+                attrs: &[::structmeta::helpers::exports::syn::Attribute],
+            ) -> ::structmeta::helpers::exports::syn::Result<::std::option::Option<Self>> {
+                ::structmeta::helpers::parse_attrs(attrs, &[#(#paths,)*], #separator)
+            }
+            #[doc = #doc_required]
+            #vis fn from_attrs_required(
+                attrs: &[::structmeta::helpers::exports::syn::Attribute],
+            ) -> ::structmeta::helpers::exports::syn::Result<Self> {
+                ::structmeta::helpers::parse_attrs_required(attrs, &[#(#paths,)*], #separator)
+            }
+        }
+    }
+}
+fn build_enum(
+    data: &DataEnum,
+    args: &ArgsForStruct,
+) -> Result<(TokenStream, TokenStream, TokenStream)> {
+    let mut names = Vec::new();
+    let mut arms = Vec::new();
+    let mut arms_to_tokens = Vec::new();
+    let mut variant_schemas = Vec::new();
+    let mut group_names = BTreeSet::new();
+    for (index, variant) in data.variants.iter().enumerate() {
+        let mut name = None;
+        for attr in &variant.attrs {
+            if attr.path().is_ident("struct_meta") {
+                let a = attr.parse_args::<ArgsForVariant>()?;
+                if let Some(a_name) = a.name {
+                    name = Some((a_name.value(), a_name.span()));
+                }
+            }
+        }
+        let (name, name_span) = name.unwrap_or_else(|| {
+            let ident = variant.ident.unraw();
+            (to_snake_case(&ident.to_string()), ident.span())
+        });
+        if names.contains(&name) {
+            bail!(name_span, "`{}` is already exists.", name);
+        }
+        let ps = Params::from_fields(&variant.fields, args)?;
+        group_names.extend(ps.group_names());
+        let variant_ident = &variant.ident;
+        let body = ps.build(&quote!(Self::#variant_ident));
+        arms.push(quote! {
+            #index => {
+                #body
+            }
+        });
+        let to_tokens = ps.build_to_tokens(&quote!(Self::#variant_ident), Some(&name));
+        arms_to_tokens.push(quote! {
+            #to_tokens
+        });
+        let doc = get_doc(&variant.attrs);
+        let schema = ps.build_schema();
+        variant_schemas.push(quote! {
+            ::structmeta::VariantSchema { name: #name, doc: #doc, schema: #schema }
+        });
+        names.push(name);
     }
+    args.check_groups_used(&group_names)?;
+    let separator = args.separator();
+    let parse = quote! {
+        match ::structmeta::helpers::parse_variant_name(input, &[#(#names,)*], #separator)? {
+            #(#arms)*
+            _ => unreachable!()
+        }
+    };
+    let to_tokens = quote! {
+        match self {
+            #(#arms_to_tokens)*
+        }
+    };
+    let schema = quote! {
+        ::structmeta::Schema {
+            params: &[],
+            flatten: &[],
+            rest: None,
+            allow_unknown: false,
+            variants: &[#(#variant_schemas,)*],
+        }
+    };
+    Ok((parse, to_tokens, schema))
 }
 struct Params<'a> {
     fields: &'a Fields,
@@ -44,8 +202,18 @@ struct Params<'a> {
     unnamed_optional: Vec<UnnamedParam<'a>>,
     unnamed_variadic: Option<UnnamedParam<'a>>,
     named: BTreeMap<String, NamedParam<'a>>,
+    flatten: Vec<FlattenParam<'a>>,
     rest: Option<RestParam<'a>>,
+    unknown: Option<UnknownParam<'a>>,
+    constraints: Vec<Constraint>,
+    groups: Vec<Group>,
     name_filter: NameFilter,
+    name_value_separators: Vec<String>,
+    separator: String,
+    unnamed_anywhere: bool,
+    allow_unknown: bool,
+    collect_errors: bool,
+    help: bool,
 }
 impl<'a> Params<'a> {
     fn from_fields(fields: &'a Fields, args: &ArgsForStruct) -> Result<Self> {
@@ -53,10 +221,22 @@ impl<'a> Params<'a> {
         let mut unnamed_optional = Vec::new();
         let mut unnamed_variadic = None;
         let mut named = BTreeMap::new();
+        let mut names = BTreeSet::new();
+        let mut flatten = Vec::new();
         let mut rest = None;
+        let mut unknown = None;
+        let mut groups: Vec<Group> = args
+            .groups
+            .iter()
+            .map(|g| Group {
+                name: g.name.value(),
+                required: g.required,
+                members: Vec::new(),
+            })
+            .collect();
         for (index, field) in fields.iter().enumerate() {
             let span = field.span();
-            match Param::from_field(index, field)? {
+            match Param::from_field(index, field, args)? {
                 Param::Unnamed(p) => {
                     if unnamed_variadic.is_some() {
                         bail!(
@@ -66,7 +246,7 @@ impl<'a> Params<'a> {
                     }
                     if p.is_vec {
                         unnamed_variadic = Some(p);
-                    } else if p.is_option {
+                    } else if p.is_option || p.default.is_some() {
                         unnamed_optional.push(p);
                     } else {
                         if !unnamed_optional.is_empty() {
@@ -79,11 +259,40 @@ impl<'a> Params<'a> {
                     }
                 }
                 Param::Named(p) => {
-                    if named.contains_key(&p.name) {
-                        bail!(p.name_span, "`{}` is already exists.", p.name);
+                    if let Some(positional) = p.to_positional() {
+                        if unnamed_variadic.is_some() {
+                            bail!(
+                                span,
+                                "cannot use unnamed parameter after variadic parameter."
+                            )
+                        }
+                        unnamed_optional.push(positional);
+                    }
+                    for (name, span) in p.names().chain(p.negated_name()) {
+                        if !names.insert(name.to_string()) {
+                            bail!(span, "`{}` is already exists.", name);
+                        }
+                    }
+                    if let Some((group_name, _)) = &p.constraints.group {
+                        if let Some(g) = groups.iter_mut().find(|g| &g.name == group_name) {
+                            g.members.push(p.name.clone());
+                        } else {
+                            groups.push(Group {
+                                name: group_name.clone(),
+                                required: false,
+                                members: vec![p.name.clone()],
+                            });
+                        }
                     }
                     named.insert(p.name.clone(), p);
                 }
+                Param::Flatten(p) => flatten.push(p),
+                Param::Unknown(p) => {
+                    if unknown.is_some() {
+                        bail!(span, "cannot use `UnknownArgs` field twice.")
+                    }
+                    unknown = Some(p);
+                }
                 Param::Rest(p) => {
                     if rest.is_some() {
                         bail!(span, "cannot use rest parameter twice.")
@@ -92,41 +301,195 @@ impl<'a> Params<'a> {
                 }
             }
         }
+        groups.retain(|g| !g.members.is_empty());
+        let mut constraints = Vec::new();
+        for p in named.values() {
+            for (other, span) in &p.constraints.conflicts_with {
+                constraints.push(Constraint::ConflictsWith {
+                    name: p.name.clone(),
+                    other: resolve_name(&named, other, *span)?,
+                });
+            }
+            for (other, span) in &p.constraints.requires {
+                constraints.push(Constraint::Requires {
+                    name: p.name.clone(),
+                    other: resolve_name(&named, other, *span)?,
+                });
+            }
+        }
+        let mut tracked = BTreeSet::new();
+        for c in &constraints {
+            let (Constraint::ConflictsWith { name, other } | Constraint::Requires { name, other }) =
+                c;
+            tracked.insert(name.clone());
+            tracked.insert(other.clone());
+        }
+        for g in &groups {
+            tracked.extend(g.members.iter().cloned());
+        }
+        for name in &tracked {
+            named.get_mut(name).unwrap().track_span = true;
+        }
         Ok(Self {
             fields,
             unnamed_required,
             unnamed_optional,
             unnamed_variadic,
             named,
+            flatten,
             rest,
+            allow_unknown: args.allow_unknown || unknown.is_some(),
+            unknown,
+            constraints,
+            groups,
             name_filter: args.name_filter(),
+            name_value_separators: args.name_value_separators(),
+            separator: args.separator(),
+            unnamed_anywhere: args.unnamed_anywhere,
+            collect_errors: args.collect_errors,
+            help: args.help,
         })
     }
-    fn build(&self) -> TokenStream {
+    fn build_to_tokens(&self, self_path: &TokenStream, variant_name: Option<&str>) -> TokenStream {
+        let mut ts = TokenStream::new();
+        if let Some(name) = variant_name {
+            let span = build_call_site_expr();
+            ts.extend(quote!(args.push_flag(#name, #span);));
+        }
+        for p in &self.unnamed_required {
+            ts.extend(p.build_to_tokens());
+        }
+        for p in self.unnamed_optional.iter().filter(|p| p.named.is_none()) {
+            ts.extend(p.build_to_tokens());
+        }
+        if let Some(p) = &self.unnamed_variadic {
+            ts.extend(p.build_to_tokens());
+        }
+        let mut named = Vec::new();
+        for p in self.named.values() {
+            named.push((p.info.index, p.build_to_tokens()));
+        }
+        for p in &self.flatten {
+            named.push((p.info.index, p.info.build_to_tokens_all()));
+        }
+        if let Some(p) = &self.rest {
+            named.push((p.info.index, p.build_to_tokens()));
+        }
+        if let Some(p) = &self.unknown {
+            named.push((p.info.index, p.build_to_tokens()));
+        }
+        named.sort_by_key(|(index, _)| *index);
+        ts.extend(named.into_iter().map(|(_, ts)| ts));
+
+        let temp_idents = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, _)| format_ident!("_value_{}", index));
+        let pat = match self.fields {
+            Fields::Named(_) => {
+                let field_idents = self.fields.iter().map(|f| &f.ident);
+                quote!(#self_path { #(#field_idents: #temp_idents,)* })
+            }
+            Fields::Unnamed(_) => quote!(#self_path(#(#temp_idents,)*)),
+            Fields::Unit => quote!(#self_path),
+        };
+        quote! {
+            #pat => {
+                #ts
+            }
+        }
+    }
+    fn build_schema(&self) -> TokenStream {
+        let mut params = Vec::new();
+        let unnamed = self
+            .unnamed_required
+            .iter()
+            .chain(&self.unnamed_optional)
+            .chain(&self.unnamed_variadic);
+        for p in unnamed.filter(|p| p.named.is_none()) {
+            params.push((p.info.index, p.build_schema()));
+        }
+        for p in self.named.values() {
+            params.push((p.info.index, p.build_schema()));
+        }
+        params.sort_by_key(|(index, _)| *index);
+        let params = params.into_iter().map(|(_, ts)| ts);
+        let flatten = self.flatten.iter().map(|p| {
+            let ty = p.info.ty;
+            quote!(<#ty as ::structmeta::ArgsSchema>::SCHEMA)
+        });
+        let rest = if let Some(p) = &self.rest {
+            let schema = p.build_schema();
+            quote!(Some(&#schema))
+        } else {
+            quote!(None)
+        };
+        let allow_unknown = self.allow_unknown;
+        quote! {
+            ::structmeta::Schema {
+                params: &[#(#params,)*],
+                flatten: &[#(#flatten,)*],
+                rest: #rest,
+                allow_unknown: #allow_unknown,
+                variants: &[],
+            }
+        }
+    }
+    fn build(&self, self_path: &TokenStream) -> TokenStream {
+        let separator = &self.separator;
         let mut is_next = false;
         let mut ts = TokenStream::new();
         let mut ctor_args = vec![TokenStream::new(); self.fields.len()];
-        for (index, p) in self.unnamed_required.iter().enumerate() {
+        if self.collect_errors {
+            ts.extend(quote!(let mut errors = ::structmeta::helpers::Errors::default();));
+        }
+        let unnamed_required: &[UnnamedParam] = if self.unnamed_anywhere {
+            &[]
+        } else {
+            &self.unnamed_required
+        };
+        for (index, p) in unnamed_required.iter().enumerate() {
             if is_next {
                 let msg = format!(
                     "expected least {} arguments but {} argument was supplied",
                     self.unnamed_required.len(),
                     index,
                 );
+                let e = quote!(::structmeta::helpers::exports::syn::Error::new(input.span(), #msg));
+                let e = if self.collect_errors {
+                    quote!(errors.combine(#e))
+                } else {
+                    e
+                };
                 ts.extend(quote! {
                     if input.is_empty () {
-                        return Err(::structmeta::helpers::exports::syn::Error::new(input.span(), #msg));
+                        return Err(#e);
                     }
-                    input.parse::<::structmeta::helpers::exports::syn::Token![,]>()?;
+                    ::structmeta::helpers::parse_arg_separator(input, #separator)?;
                 });
             }
             is_next = true;
-            ts.extend(p.info.build_let_parse());
-            p.build_ctor_arg(false, &mut ctor_args);
+            ts.extend(p.info.build_let_parse(self.collect_errors, separator));
+            p.build_ctor_arg(self.collect_errors, &mut ctor_args);
         }
 
         let mut arms_unnamed = Vec::new();
-        for (index, p) in self.unnamed_optional.iter().enumerate() {
+        let unnamed_required_in_loop: &[UnnamedParam] = if self.unnamed_anywhere {
+            &self.unnamed_required
+        } else {
+            &[]
+        };
+        for (index, p) in unnamed_required_in_loop
+            .iter()
+            .chain(&self.unnamed_optional)
+            .enumerate()
+        {
+            if let Some(name) = &p.named {
+                arms_unnamed
+                    .push(self.named[name].build_arm_parse_positional(index, self.collect_errors));
+                continue;
+            }
             ts.extend(p.info.build_let_none());
             arms_unnamed.push(p.build_arm_parse_value(index));
             p.build_ctor_arg(true, &mut ctor_args);
@@ -140,61 +503,65 @@ impl<'a> Params<'a> {
                 _ => { return Err(input.error("too many unnamed parameter")); }
             });
         }
+        // The input is empty when the constructor arguments are evaluated,
+        // so `input.span()` is the span of the end of the argument list.
+        let end_span = quote!(input.span());
         for p in self.named.values() {
-            ts.extend(p.build_let());
-            p.build_ctor_arg(&mut ctor_args);
-        }
-        let (flag_ps, flag_rest) = self.named_ps(|p| p.is_flag());
-        let (name_value_ps, name_value_rest) = self.named_ps(|p| p.is_name_value());
-        let (name_args_ps, name_args_rest) = self.named_ps(|p| p.is_name_args());
-
-        let mut arms_named = Vec::new();
-        for (index, p) in flag_ps.iter().enumerate() {
-            arms_named.push(p.build_arm_parse(index, ArgKind::Flag));
+            ts.extend(p.build_let(self.collect_errors));
+            p.build_ctor_arg(&end_span, &mut ctor_args);
         }
-        for (index, p) in name_value_ps.iter().enumerate() {
-            arms_named.push(p.build_arm_parse(index, ArgKind::NameValue));
-        }
-        for (index, p) in name_args_ps.iter().enumerate() {
-            arms_named.push(p.build_arm_parse(index, ArgKind::NameArgs));
+        for p in &self.flatten {
+            ts.extend(p.build_let());
+            p.build_ctor_arg(self.collect_errors, &end_span, &mut ctor_args);
         }
         if let Some(p) = &self.rest {
             ts.extend(p.build_let());
             p.build_ctor_arg(&mut ctor_args);
-            if flag_rest {
-                arms_named.push(p.build_arm_parse(ArgKind::Flag));
-            }
-            if name_value_rest {
-                arms_named.push(p.build_arm_parse(ArgKind::NameValue));
-            }
-            if name_args_rest {
-                arms_named.push(p.build_arm_parse(ArgKind::NameArgs));
-            }
         }
-
-        let flag_names = NamedParam::names(&flag_ps);
-        let name_value_names = NamedParam::names(&name_value_ps);
-        let name_args_names = NamedParam::names(&name_args_ps);
-        let no_unnamed = self.unnamed_optional.is_empty() && self.unnamed_variadic.is_none();
-        let ctor_args = match &self.fields {
-            Fields::Named(_) => {
-                quote!({ #(#ctor_args,)*})
-            }
-            Fields::Unnamed(_) => {
-                quote!(( #(#ctor_args,)*))
-            }
-            Fields::Unit => {
-                quote!()
+        if let Some(p) = &self.unknown {
+            ts.extend(p.build_let());
+            p.build_ctor_arg(&mut ctor_args);
+        }
+        let (
+            ts_names,
+            [
+                flag_names,
+                name_value_names,
+                name_args_names,
+                name_args_opens,
+                name_args_delimiters,
+                name_value_lookahead,
+                help_params,
+            ],
+        ) = self.build_names(false);
+        ts.extend(ts_names);
+        let [flag_rest, name_value_rest, name_args_rest] = self.rest_kinds();
+        let name_args_rest = match &self.rest {
+            Some(p) if name_args_rest => {
+                let open = p.delimiters.chars().next().unwrap();
+                quote!(Some(#open))
             }
+            _ => quote!(None),
         };
+        let arms_named = self.build_arms_named(self.collect_errors, false);
 
-        let ts_parse_unnamed = if !self.unnamed_optional.is_empty()
-            || self.unnamed_variadic.is_some()
-        {
-            quote! {
-                if named_used {
-                    return Err(input.error("cannot use unnamed parameter after named parameter"));
+        let no_unnamed = unnamed_required_in_loop.is_empty()
+            && self.unnamed_optional.is_empty()
+            && self.unnamed_variadic.is_none();
+        let ctor_args = self.build_ctor_args(ctor_args);
+
+        let ts_parse_unnamed = if !no_unnamed {
+            let check_named_used = if self.unnamed_anywhere {
+                quote!()
+            } else {
+                quote! {
+                    if named_used {
+                        return Err(input.error("cannot use unnamed parameter after named parameter"));
+                    }
                 }
+            };
+            quote! {
+                #check_named_used
                 match unnamed_index {
                     #(#arms_unnamed)*
                 }
@@ -206,6 +573,87 @@ impl<'a> Params<'a> {
             }
         };
         let name_filter = self.name_filter.to_code();
+        let allow_unknown = self.allow_unknown;
+        let name_value_separators = &self.name_value_separators;
+        let help_params = if self.help { help_params } else { quote!(&[]) };
+
+        let ts_parse_arg = quote! {
+            if let Some((index, span)) = ::structmeta::helpers::try_parse_name(input,
+                #flag_names,
+                #flag_rest,
+                #name_value_names,
+                #name_value_rest,
+                #name_value_lookahead,
+                #name_args_names,
+                #name_args_opens,
+                #name_args_rest,
+                #name_args_delimiters,
+                #no_unnamed,
+                #allow_unknown,
+                #name_filter,
+                &[#(#name_value_separators,)*],
+                #separator,
+                #help_params)?
+            {
+                named_used = true;
+                match index {
+                    #(#arms_named)*
+                    _ => unreachable!()
+                }
+
+            } else {
+                #ts_parse_unnamed
+            }
+        };
+        let constraint_checks = self.build_constraint_checks(&end_span);
+        let unnamed_required_check = if unnamed_required_in_loop.is_empty() {
+            quote!()
+        } else {
+            let len = unnamed_required_in_loop.len();
+            let e = quote! {
+                ::structmeta::helpers::exports::syn::Error::new(
+                    input.span(),
+                    ::std::format!("expected least {} arguments but {} argument was supplied", #len, unnamed_index),
+                )
+            };
+            let e = if self.collect_errors {
+                quote!(errors.push(#e))
+            } else {
+                quote!(return Err(#e))
+            };
+            quote! {
+                if unnamed_index < #len {
+                    #e;
+                }
+            }
+        };
+        let (ts_parse_arg, ts_check) = if self.collect_errors {
+            let missing_checks = self
+                .named
+                .values()
+                .map(|p| p.build_missing_check(&end_span));
+            let finish_checks = self.flatten.iter().map(|p| p.build_finish_check(&end_span));
+            (
+                quote! {
+                    errors.parse_arg(input, #separator, || {
+                        #ts_parse_arg
+                        Ok(())
+                    });
+                },
+                quote! {
+                    #unnamed_required_check
+                    #(#missing_checks)*
+                    #(errors.push_result(#constraint_checks);)*
+                    #(#finish_checks)*
+                    errors.finish()?;
+                },
+            )
+        } else {
+            (
+                ts_parse_arg,
+                quote!(#unnamed_required_check #(#constraint_checks?;)*),
+            )
+        };
 
         ts.extend(quote! {
             let mut is_next = #is_next;
@@ -213,63 +661,365 @@ impl<'a> Params<'a> {
             let mut named_used = false;
             while !input.is_empty() {
                 if is_next {
-                    input.parse::<::structmeta::helpers::exports::syn::Token![,]>()?;
+                    ::structmeta::helpers::parse_arg_separator(input, #separator)?;
                     if input.is_empty() {
                         break;
                     }
                 }
                 is_next = true;
-                if let Some((index, span)) = ::structmeta::helpers::try_parse_name(input,
-                    &[#(#flag_names,)*],
-                    #flag_rest,
-                    &[#(#name_value_names,)*],
-                    #name_value_rest,
-                    &[#(#name_args_names,)*],
-                    #name_args_rest,
-                    #no_unnamed,
-                    #name_filter)?
-                {
-                    named_used = true;
-                    match index {
-                        #(#arms_named)*
-                        _ => unreachable!()
-                    }
-
-                } else {
-                    #ts_parse_unnamed
-                }
+                #ts_parse_arg
             }
-            Ok(Self #ctor_args)
+            #ts_check
+            Ok(#self_path #ctor_args)
         });
 
         ts
     }
-    fn named_ps(&self, f: impl Fn(&NamedParamType<'a>) -> bool) -> (Vec<&NamedParam<'a>>, bool) {
+    fn build_flatten(&self) -> Option<TokenStream> {
+        if !self.unnamed_required.is_empty()
+            || !self.unnamed_optional.is_empty()
+            || self.unnamed_variadic.is_some()
+            || self.rest.is_some()
+            || self.unknown.is_some()
+        {
+            return None;
+        }
+        let mut temps = Vec::new();
+        let mut state_tys = Vec::new();
+        let mut inits = Vec::new();
+        let mut ctor_args = vec![TokenStream::new(); self.fields.len()];
+        for p in self.named.values() {
+            temps.push(p.info.temp_ident.clone());
+            state_tys.push(p.build_state_ty());
+            inits.push(p.build_state_init());
+            if p.track_span {
+                temps.push(p.span_ident());
+                state_tys.push(quote!(
+                    Option<::structmeta::helpers::exports::proc_macro2::Span>
+                ));
+                inits.push(quote!(None));
+            }
+            p.build_ctor_arg(&quote!(span), &mut ctor_args);
+        }
+        for p in &self.flatten {
+            let ty = p.info.ty;
+            temps.push(p.info.temp_ident.clone());
+            state_tys.push(quote!(<#ty as ::structmeta::helpers::Flatten>::State));
+            inits.push(quote!(<#ty as ::structmeta::helpers::Flatten>::new_state()));
+            p.build_ctor_arg(false, &quote!(span), &mut ctor_args);
+        }
+        let ctor_args = self.build_ctor_args(ctor_args);
+        let (ts_names, _) = self.build_names(true);
+        let (ts_ranges, _) = self.build_names(false);
+        let arms_named = self.build_arms_named(false, true);
+        let constraint_checks = self.build_constraint_checks(&quote!(span));
+        Some(quote! {
+            type State = (#(#state_tys,)*);
+            fn names() -> ::structmeta::helpers::exports::syn::Result<::structmeta::helpers::FlattenNames> {
+                #ts_names
+                Ok(names)
+            }
+            #[allow(clippy::unused_unit)]
+            fn new_state() -> Self::State {
+                (#(#inits,)*)
+            }
+            #[allow(unused_variables, unreachable_code)]
+            fn parse_arg(
+                state: &mut Self::State,
+                input: ::structmeta::helpers::exports::syn::parse::ParseStream<'_>,
+                index: ::structmeta::helpers::NameIndex,
+                span: ::structmeta::helpers::exports::proc_macro2::Span,
+            ) -> ::structmeta::helpers::exports::syn::Result<()> {
+                #ts_ranges
+                let (#(#temps,)*) = state;
+                match index {
+                    #(#arms_named)*
+                    _ => unreachable!()
+                }
+                Ok(())
+            }
+            #[allow(unused_variables)]
+            fn finish(
+                state: Self::State,
+                span: ::structmeta::helpers::exports::proc_macro2::Span,
+            ) -> ::structmeta::helpers::exports::syn::Result<Self> {
+                let (#(#temps,)*) = state;
+                #(#constraint_checks?;)*
+                Ok(Self #ctor_args)
+            }
+        })
+    }
+    fn build_constraint_checks(&self, end_span: &TokenStream) -> Vec<TokenStream> {
+        let mut checks = Vec::new();
+        for c in &self.constraints {
+            let (f, name, other) = match c {
+                Constraint::ConflictsWith { name, other } => (quote!(check_conflicts), name, other),
+                Constraint::Requires { name, other } => (quote!(check_requires), name, other),
+            };
+            let span = self.named[name].span_ident();
+            let other_span = self.named[other].span_ident();
+            checks.push(quote!(::structmeta::helpers::#f(#name, #span, #other, #other_span)));
+        }
+        for g in &self.groups {
+            let group_name = &g.name;
+            let required = g.required;
+            let members = &g.members;
+            let spans = members.iter().map(|name| self.named[name].span_ident());
+            checks.push(quote! {
+                ::structmeta::helpers::check_group(#group_name, &[#((#members, #spans),)*], #required, #end_span)
+            });
+        }
+        checks
+    }
+    fn group_names(&self) -> BTreeSet<String> {
+        self.groups.iter().map(|g| g.name.clone()).collect()
+    }
+    fn build_ctor_args(&self, ctor_args: Vec<TokenStream>) -> TokenStream {
+        match &self.fields {
+            Fields::Named(_) => {
+                quote!({ #(#ctor_args,)*})
+            }
+            Fields::Unnamed(_) => {
+                quote!(( #(#ctor_args,)*))
+            }
+            Fields::Unit => {
+                quote!()
+            }
+        }
+    }
+    fn build_names(&self, dynamic: bool) -> (TokenStream, [TokenStream; 7]) {
+        let [mut flag_names, name_value_names, name_args_names] = self
+            .named_kinds()
+            .map(|ps| NamedParam::names_and_indexes(&ps).0);
+        flag_names.extend(self.named.values().filter_map(|p| p.negated.as_deref()));
+        let [_, _, name_args_params] = self.named_kinds();
+        let name_args_opens = name_args_params
+            .iter()
+            .flat_map(|p| p.names().map(|_| p.delimiters.chars().next().unwrap()));
+        let name_args_delimiters = self.name_args_delimiters();
+        // `name = true` for a flag is recognized only by the name of the flag,
+        // so flags do not make `name = ...` with other names a named argument.
+        let name_value_lookahead = self.named.values().any(|p| {
+            p.ty.is_name_value() && !matches!(p.ty, NamedParamType::Bool | NamedParamType::Flag)
+        });
+        let help_params = self.help_params();
+        if !dynamic && self.flatten.is_empty() {
+            return (
+                quote!(),
+                [
+                    quote!(&[#(#flag_names,)*]),
+                    quote!(&[#(#name_value_names,)*]),
+                    quote!(&[#(#name_args_names,)*]),
+                    quote!(&[#(#name_args_opens,)*]),
+                    quote!(#name_args_delimiters),
+                    quote!(#name_value_lookahead),
+                    quote!(&[#(#help_params,)*]),
+                ],
+            );
+        }
+        let appends = self.flatten.iter().map(|p| p.build_append_names());
         (
-            self.named.values().filter(|p| f(&p.ty)).collect(),
+            quote! {
+                let mut names = ::structmeta::helpers::FlattenNames {
+                    flag: vec![#(#flag_names,)*],
+                    name_value: vec![#(#name_value_names,)*],
+                    name_args: vec![#(#name_args_names,)*],
+                    name_args_opens: vec![#(#name_args_opens,)*],
+                    name_args_delimiters: #name_args_delimiters.to_string(),
+                    name_value_lookahead: #name_value_lookahead,
+                    help_params: vec![#(#help_params,)*],
+                };
+                #(#appends)*
+            },
+            [
+                quote!(&names.flag),
+                quote!(&names.name_value),
+                quote!(&names.name_args),
+                quote!(&names.name_args_opens),
+                quote!(&names.name_args_delimiters),
+                quote!(names.name_value_lookahead),
+                quote!(&names.help_params),
+            ],
+        )
+    }
+    fn help_params(&self) -> Vec<TokenStream> {
+        let mut params = Vec::new();
+        for p in self.named.values() {
+            for (name, _) in p.names() {
+                p.push_help_params(name, &mut params);
+            }
+            if let Some((name, _)) = p.negated_name() {
+                params.push(quote!(::structmeta::helpers::HelpParam::Flag(#name)));
+            }
+        }
+        params
+    }
+    fn name_args_delimiters(&self) -> String {
+        let mut delimiters = String::new();
+        let named = self.named.values().map(|p| (&p.ty, &p.delimiters));
+        let rest = self.rest.iter().map(|p| (&p.ty, &p.delimiters));
+        for (ty, ds) in named.chain(rest) {
+            if ty.is_name_args() {
+                for c in ds.chars() {
+                    if !delimiters.contains(c) {
+                        delimiters.push(c);
+                    }
+                }
+            }
+        }
+        delimiters
+    }
+    fn build_arms_named(&self, collect_errors: bool, by_ref: bool) -> Vec<TokenStream> {
+        let mut arms = Vec::new();
+        for (kind, ps) in ArgKind::ALL.into_iter().zip(self.named_kinds()) {
+            let (names, indexes) = NamedParam::names_and_indexes(&ps);
+            for (p, indexes) in ps.iter().zip(&indexes) {
+                arms.push(p.build_arm_parse(indexes, kind, collect_errors, by_ref));
+            }
+            if kind == ArgKind::Flag {
+                let negated = self.named.values().filter(|p| p.negated.is_some());
+                for (index, p) in (names.len()..).zip(negated) {
+                    arms.push(p.build_arm_parse_negated(index, collect_errors, by_ref));
+                }
+            }
+        }
+        for p in &self.flatten {
+            arms.extend(p.build_arms_parse(by_ref));
+        }
+        if let Some(p) = &self.rest {
+            for (kind, rest) in ArgKind::ALL.into_iter().zip(self.rest_kinds()) {
+                if rest {
+                    arms.push(p.build_arm_parse(kind));
+                }
+            }
+        }
+        if self.allow_unknown {
+            let separator = &self.separator;
+            let push = if let Some(p) = &self.unknown {
+                p.build_push()
+            } else {
+                quote!()
+            };
+            arms.push(quote! {
+                ::structmeta::helpers::NameIndex::Unknown(name) => {
+                    let tokens = ::structmeta::helpers::parse_unknown_arg(input, #separator)?;
+                    #push
+                }
+            });
+        }
+        arms
+    }
+    fn named_kinds(&self) -> [Vec<&NamedParam<'a>>; 3] {
+        ArgKind::ALL.map(|kind| self.named.values().filter(|p| p.ty.is_kind(kind)).collect())
+    }
+    fn rest_kinds(&self) -> [bool; 3] {
+        ArgKind::ALL.map(|kind| {
             if let Some(p) = &self.rest {
-                f(&p.ty)
+                if p.kind.is_flag_only() {
+                    kind == ArgKind::Flag
+                } else {
+                    p.ty.is_kind(kind)
+                }
             } else {
                 false
-            },
-        )
+            }
+        })
+    }
+}
+
+fn resolve_name(named: &BTreeMap<String, NamedParam>, name: &str, span: Span) -> Result<String> {
+    for p in named.values() {
+        if p.names().any(|(n, _)| n == name) {
+            return Ok(p.name.clone());
+        }
+    }
+    bail!(span, "cannot find parameter `{}`.", name)
+}
+
+enum Constraint {
+    ConflictsWith { name: String, other: String },
+    Requires { name: String, other: String },
+}
+
+struct Group {
+    name: String,
+    required: bool,
+    members: Vec<String>,
+}
+
+#[derive(Default)]
+struct Constraints {
+    conflicts_with: Vec<(String, Span)>,
+    requires: Vec<(String, Span)>,
+    group: Option<(String, Span)>,
+}
+impl Constraints {
+    fn first_span(&self) -> Option<Span> {
+        self.conflicts_with
+            .iter()
+            .chain(&self.requires)
+            .chain(&self.group)
+            .map(|(_, span)| *span)
+            .next()
     }
 }
 
 enum Param<'a> {
     Unnamed(UnnamedParam<'a>),
     Named(NamedParam<'a>),
+    Flatten(FlattenParam<'a>),
     Rest(RestParam<'a>),
+    Unknown(UnknownParam<'a>),
 }
 
 impl<'a> Param<'a> {
-    fn from_field(index: usize, field: &'a Field) -> Result<Self> {
+    fn from_field(index: usize, field: &'a Field, args: &ArgsForStruct) -> Result<Self> {
         let mut name = None;
+        let mut negated = None;
+        let mut negatable = None;
+        let mut delimiters = None;
+        let mut separator = None;
         let mut name_specified = false;
         let mut unnamed = false;
+        let mut default = None;
+        let mut aliases = Vec::new();
+        let mut flatten = None;
+        let mut repeated = None;
+        let mut rest = None;
+        let mut constraints = Constraints::default();
         for attr in &field.attrs {
             if attr.path().is_ident("struct_meta") {
                 let a = attr.parse_args::<ArgsForField>()?;
+                if let Some(a_rest) = a.rest {
+                    rest = Some(a_rest);
+                }
+                if let Some(a_repeated) = a.repeated {
+                    repeated = Some(a_repeated);
+                }
+                if let Some(a_negatable) = a.negatable {
+                    negatable = Some(a_negatable);
+                }
+                if let Some(a_delimiters) = a.delimiters {
+                    delimiters = Some(a_delimiters);
+                }
+                if let Some(a_separator) = a.separator {
+                    separator = Some(a_separator);
+                }
+                for s in a.conflicts_with {
+                    constraints.conflicts_with.push((s.value(), s.span()));
+                }
+                for s in a.requires {
+                    constraints.requires.push((s.value(), s.span()));
+                }
+                if let Some(s) = a.group {
+                    constraints.group = Some((s.value(), s.span()));
+                }
+                if let Some(a_flatten) = a.flatten {
+                    flatten = Some(a_flatten);
+                }
+                for alias in a.aliases {
+                    aliases.push((alias.value(), alias.span()));
+                }
                 if let Some(a_name) = a.name {
                     name = Some((a_name.value(), a_name.span()));
                     name_specified = true;
@@ -277,49 +1027,259 @@ impl<'a> Param<'a> {
                 if a.unnamed {
                     unnamed = true;
                 }
+                if let Some(a_default) = a.default {
+                    default = Some(a_default);
+                }
+            }
+        }
+        if let Some(span) = flatten {
+            if let Some(s) = &delimiters {
+                bail!(s.span(), "`delimiters` cannot be used with `flatten`.")
+            }
+            if let Some(s) = &separator {
+                bail!(s.span(), "`separator` cannot be used with `flatten`.")
+            }
+            if name_specified
+                || unnamed
+                || !aliases.is_empty()
+                || default.is_some()
+                || repeated.is_some()
+                || rest.is_some()
+                || negatable.is_some()
+            {
+                bail!(
+                    span,
+                    "`flatten` cannot be used with `name`, `alias`, `unnamed`, `default`, `repeated`, `rest` or `negatable`."
+                )
+            }
+            if let Some(span) = constraints.first_span() {
+                bail!(
+                    span,
+                    "`conflicts_with`, `requires` and `group` cannot be used with `flatten`."
+                )
             }
+            let info = ParamInfo::new(index, field, &field.ty);
+            return Ok(Param::Flatten(FlattenParam { info }));
         }
-        if name.is_none() {
-            if let Some(ident) = &field.ident {
-                name = Some((ident.unraw().to_string(), ident.span()));
+        if let Some((name, _)) = &name {
+            negated = Some(format!("no_{name}"));
+        } else if let Some(ident) = &field.ident {
+            let mut s = ident.unraw().to_string();
+            let mut s_negated = format!("no_{s}");
+            if let Some(rename_all) = args.rename_all {
+                s = rename_all.apply(&s);
+                s_negated = rename_all.apply(&s_negated);
             }
+            name = Some((s, ident.span()));
+            negated = Some(s_negated);
+        }
+        let positional = unnamed && name_specified;
+        if unnamed && !name_specified {
+            name = None;
+        }
+
+        if !name_specified && is_unknown_args(&field.ty) {
+            if unnamed
+                || default.is_some()
+                || !aliases.is_empty()
+                || repeated.is_some()
+                || rest.is_some()
+                || delimiters.is_some()
+                || separator.is_some()
+                || constraints.first_span().is_some()
+            {
+                bail!(
+                    field.span(),
+                    "`UnknownArgs` field cannot be used with `unnamed`, `default`, `alias`, `repeated`, `rest`, `delimiters`, `separator`, `conflicts_with`, `requires` or `group`."
+                )
+            }
+            let info = ParamInfo::new(index, field, &field.ty);
+            return Ok(Param::Unknown(UnknownParam { info }));
+        }
+
+        let mut rest_kind = None;
+        let mut is_option = false;
+
+        if let (Some(span), true) = (rest, name_specified) {
+            bail!(span, "`rest` cannot be used with `name`.")
+        }
+        let ty = if let (false, Some((kind, ty))) =
+            (name_specified, get_rest_element(&field.ty, rest.is_some()))
+        {
+            rest_kind = Some(kind);
+            ty
+        } else if let Some(span) = rest {
+            bail!(
+                span,
+                "`rest` cannot be used with this field type. (`HashMap<String, _>`, `HashMap<Ident, _>`, `BTreeMap<String, _>`, `Vec<(Ident, _)>`, `RestArgs<_>`, `Vec<Ident>`, `HashSet<String>` or `BTreeSet<String>` is expected)"
+            )
+        } else if let Some(ty) = get_option_element(&field.ty) {
+            is_option = true;
+            ty
+        } else {
+            &field.ty
+        };
+
+        let is_map = rest_kind.is_some();
+        let mut is_repeated = false;
+        if let Some(span) = repeated {
+            if is_map {
+                bail!(span, "`repeated` cannot be used with rest parameter.")
+            }
+            if name.is_none() {
+                bail!(span, "`repeated` cannot be used with unnamed parameter.")
+            }
+            if is_option {
+                bail!(span, "`repeated` cannot be used with `Option`.")
+            }
+            if get_vec_element(ty).is_none() {
+                bail!(span, "`repeated` can be used only with `Vec`.")
+            }
+            is_repeated = true;
+        } else if !is_map && !is_option && name.is_some() {
+            if let Some(e) = get_vec_element(ty) {
+                is_repeated =
+                    get_name_value_element(e).is_some() || get_name_args_element(e).is_some();
+            }
+        }
+        let ty = if is_repeated {
+            get_vec_element(ty).unwrap()
+        } else {
+            ty
+        };
+
+        if let Some(default) = &default {
+            if is_repeated {
+                bail!(
+                    default.span,
+                    "`default` cannot be used with repeated parameter."
+                )
+            }
+            if is_map {
+                bail!(
+                    default.span,
+                    "`default` cannot be used with rest parameter."
+                )
+            }
+            if is_option {
+                bail!(default.span, "`default` cannot be used with `Option`.")
+            }
+        }
+
+        if let Some((_, span)) = aliases.first() {
+            if is_map {
+                bail!(*span, "`alias` cannot be used with rest parameter.")
+            }
+            if name.is_none() {
+                bail!(*span, "`alias` cannot be used with unnamed parameter.")
+            }
+        }
+
+        if let Some(span) = constraints.first_span() {
+            if is_map {
+                bail!(
+                    span,
+                    "`conflicts_with`, `requires` and `group` cannot be used with rest parameter."
+                )
+            }
+            if name.is_none() {
+                bail!(
+                    span,
+                    "`conflicts_with`, `requires` and `group` cannot be used with unnamed parameter."
+                )
+            }
+        }
+
+        let info = ParamInfo::new(index, field, ty);
+        let ty = if rest_kind.is_some_and(|kind| kind.is_flag_only()) {
+            NamedParamType::Flag
+        } else {
+            let may_flag = name.is_some()
+                && !positional
+                && !is_map
+                && !is_repeated
+                && (!is_option || is_bool(ty));
+            NamedParamType::from_type(ty, may_flag)
+        };
+        let is_negatable = matches!(ty, NamedParamType::Bool | NamedParamType::Flag)
+            && rest_kind.is_none()
+            && name.is_some();
+        if let (Some(span), false) = (negatable, is_negatable) {
+            bail!(
+                span,
+                "`negatable` can be used only with `bool`, `Option<bool>` or `Flag`."
+            )
         }
-        if unnamed {
-            name = None;
+        if !(is_negatable && (negatable.is_some() || args.negatable)) {
+            negated = None;
         }
-
-        let mut is_map = false;
-        let mut is_option = false;
-
-        let ty = if let (false, Some(ty)) = (name_specified, get_hash_map_string_element(&field.ty))
-        {
-            is_map = true;
-            ty
-        } else if let Some(ty) = get_option_element(&field.ty) {
-            is_option = true;
-            ty
+        let delimiters = if let Some(s) = delimiters {
+            if name.is_none() || !ty.is_name_args() {
+                bail!(
+                    s.span(),
+                    "`delimiters` can be used only with `NameArgs<_>` or `Vec<_>` named parameter."
+                )
+            }
+            s.value()
         } else {
-            &field.ty
+            args.delimiters()
         };
-
-        let info = ParamInfo::new(index, field, ty);
-        let ty = NamedParamType::from_type(ty, !is_map && !is_option);
-        let this = if is_map {
-            Param::Rest(RestParam { info, ty })
+        if let Some(s) = &separator {
+            if !ty.is_list() {
+                bail!(
+                    s.span(),
+                    "`separator` can be used only with `Vec<_>` or `NameArgs<Vec<_>>`."
+                )
+            }
+        }
+        if positional && (is_repeated || !matches!(ty, NamedParamType::Value { is_vec: false, .. }))
+        {
+            bail!(
+                info.span(),
+                "`unnamed` cannot be used with `name` for this field type."
+            )
+        }
+        let this = if let Some(kind) = rest_kind {
+            Param::Rest(RestParam {
+                info,
+                ty,
+                kind,
+                delimiters,
+                separator: separator.map_or_else(|| ",".to_string(), |s| s.value()),
+            })
         } else if let Some((name, name_span)) = name {
             Param::Named(NamedParam {
                 info,
                 name,
                 name_span,
+                aliases,
+                negated,
                 ty,
+                delimiters,
+                separator: separator.map_or_else(|| ",".to_string(), |s| s.value()),
+                name_value_separator: args.name_value_separators().swap_remove(0),
                 is_option,
+                is_repeated,
+                positional,
+                default,
+                constraints,
+                track_span: false,
             })
         } else if let NamedParamType::Value { ty, is_vec } = ty {
+            if let (Some(default), true) = (&default, is_vec) {
+                bail!(
+                    default.span,
+                    "`default` cannot be used with variadic parameter."
+                )
+            }
             Param::Unnamed(UnnamedParam {
                 info,
                 ty,
                 is_option,
                 is_vec,
+                default,
+                separator: separator.map(|s| s.value()),
+                named: None,
             })
         } else {
             bail!(
@@ -350,6 +1310,10 @@ impl<'a> ParamInfo<'a> {
     fn span(&self) -> Span {
         self.field.span()
     }
+    fn build_to_tokens_all(&self) -> TokenStream {
+        let temp_ident = &self.temp_ident;
+        quote!(args.push_tokens(::structmeta::helpers::exports::quote::ToTokens::to_token_stream(#temp_ident));)
+    }
     fn build_let_none(&self) -> TokenStream {
         let temp_ident = &self.temp_ident;
         let ty = &self.ty;
@@ -360,24 +1324,69 @@ impl<'a> ParamInfo<'a> {
         let ty = &self.ty;
         quote!(let mut #temp_ident = <#ty>::new();)
     }
-    fn build_let_parse(&self) -> TokenStream {
+    fn build_let_parse(&self, collect_errors: bool, separator: &str) -> TokenStream {
         let temp_ident = &self.temp_ident;
-        let ty = &self.field.ty;
-        quote_spanned!(self.span()=> let #temp_ident = input.parse::<#ty>()?;)
+        let parse = build_parse_call(&self.field.ty, &quote!(input), self.span());
+        if collect_errors {
+            quote_spanned!(self.span()=> let #temp_ident = errors.parse_arg(input, #separator, || #parse);)
+        } else {
+            quote_spanned!(self.span()=> let #temp_ident = #parse?;)
+        }
     }
 }
 
 struct RestParam<'a> {
     info: ParamInfo<'a>,
     ty: NamedParamType<'a>,
+    kind: RestKind,
+    delimiters: String,
+    separator: String,
+}
+
+#[derive(Clone, Copy)]
+enum RestKind {
+    HashMapString,
+    HashMapIdent,
+    BTreeMapString,
+    VecIdent,
+    RestArgs,
+    FlagVecIdent,
+    FlagHashSetString,
+    FlagBTreeSetString,
+}
+impl RestKind {
+    fn is_flag_only(self) -> bool {
+        matches!(
+            self,
+            Self::FlagVecIdent | Self::FlagHashSetString | Self::FlagBTreeSetString
+        )
+    }
 }
 
 struct NamedParam<'a> {
     info: ParamInfo<'a>,
     name: String,
     name_span: Span,
+    aliases: Vec<(String, Span)>,
+    negated: Option<String>,
     ty: NamedParamType<'a>,
+    delimiters: String,
+    separator: String,
+    name_value_separator: String,
     is_option: bool,
+    is_repeated: bool,
+    positional: bool,
+    default: Option<DefaultValue>,
+    constraints: Constraints,
+    track_span: bool,
+}
+
+struct FlattenParam<'a> {
+    info: ParamInfo<'a>,
+}
+
+struct UnknownParam<'a> {
+    info: ParamInfo<'a>,
 }
 
 struct UnnamedParam<'a> {
@@ -385,64 +1394,575 @@ struct UnnamedParam<'a> {
     ty: &'a Type,
     is_option: bool,
     is_vec: bool,
+    default: Option<DefaultValue>,
+    separator: Option<String>,
+    /// The name of the named parameter that can also be specified by this unnamed parameter.
+    named: Option<String>,
 }
-impl NamedParam<'_> {
-    fn build_let(&self) -> TokenStream {
+impl UnnamedParam<'_> {
+    fn build_schema(&self) -> TokenStream {
+        let occurrence = if self.is_vec {
+            quote!(Variadic)
+        } else if self.is_option || self.default.is_some() {
+            quote!(Optional)
+        } else {
+            quote!(Required)
+        };
+        build_param_schema(None, &[], None, quote!(Unnamed), occurrence, &self.info)
+    }
+    fn build_to_tokens(&self) -> TokenStream {
         let temp_ident = &self.info.temp_ident;
-        quote!(let mut #temp_ident = None;)
+        let value = build_to_tokens_value(self.ty, false, &quote!(value));
+        if let Some(separator) = &self.separator {
+            let values = build_to_tokens_value(self.ty, true, &quote!(#temp_ident));
+            quote!(args.push_unnamed_list(#values, #separator);)
+        } else if self.is_vec {
+            quote!(for value in #temp_ident { args.push_unnamed(#value); })
+        } else if self.is_option {
+            quote!(if let Some(value) = #temp_ident { args.push_unnamed(#value); })
+        } else {
+            quote!(let value = #temp_ident; args.push_unnamed(#value);)
+        }
+    }
+}
+impl RestParam<'_> {
+    fn build_schema(&self) -> TokenStream {
+        let style = if self.kind.is_flag_only() {
+            quote!(Flag)
+        } else {
+            self.ty.build_schema_style()
+        };
+        build_param_schema(None, &[], None, style, quote!(Repeated), &self.info)
     }
-    fn build_arm_parse(&self, index: usize, kind: ArgKind) -> TokenStream {
+    fn build_to_tokens(&self) -> TokenStream {
         let temp_ident = &self.info.temp_ident;
-        let msg = format!("parameter `{}` specified more than once", self.name);
+        match self.kind {
+            RestKind::HashMapString | RestKind::BTreeMapString => {
+                let push = self.ty.build_to_tokens(
+                    &quote!(name),
+                    &build_call_site_expr(),
+                    &self.delimiters,
+                    &self.separator,
+                );
+                quote!(for (name, value) in #temp_ident { #push })
+            }
+            RestKind::HashMapIdent | RestKind::VecIdent | RestKind::RestArgs => {
+                let push = self.ty.build_to_tokens(
+                    &quote!(&name.to_string()),
+                    &quote!(name.span()),
+                    &self.delimiters,
+                    &self.separator,
+                );
+                quote!(for (name, value) in #temp_ident { #push })
+            }
+            RestKind::FlagVecIdent => {
+                quote!(for name in #temp_ident { args.push_flag(&name.to_string(), name.span()); })
+            }
+            RestKind::FlagHashSetString | RestKind::FlagBTreeSetString => {
+                let span = build_call_site_expr();
+                quote!(for name in #temp_ident { args.push_flag(name, #span); })
+            }
+        }
+    }
+}
+impl<'a> NamedParam<'a> {
+    fn build_schema(&self) -> TokenStream {
+        let occurrence = if self.is_repeated {
+            quote!(Repeated)
+        } else if self.is_option
+            || self.default.is_some()
+            || matches!(self.ty, NamedParamType::Bool | NamedParamType::Flag)
+        {
+            quote!(Optional)
+        } else {
+            quote!(Required)
+        };
+        let aliases: Vec<_> = self.aliases.iter().map(|(name, _)| name.as_str()).collect();
+        build_param_schema(
+            Some(&self.name),
+            &aliases,
+            self.negated.as_deref(),
+            self.ty.build_schema_style(),
+            occurrence,
+            &self.info,
+        )
+    }
+    fn to_positional(&self) -> Option<UnnamedParam<'a>> {
+        if !self.positional {
+            return None;
+        }
+        let NamedParamType::Value { ty, .. } = self.ty else {
+            unreachable!()
+        };
+        Some(UnnamedParam {
+            info: ParamInfo::new(self.info.index, self.info.field, self.info.ty),
+            ty,
+            is_option: true,
+            is_vec: false,
+            default: None,
+            separator: None,
+            named: Some(self.name.clone()),
+        })
+    }
+    fn build_arm_parse_positional(&self, index: usize, collect_errors: bool) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        let NamedParamType::Value { ty, .. } = self.ty else {
+            unreachable!()
+        };
+        let span = self.info.field.span();
+        let expr = build_parse_expr(ty, span);
+        let check_used = self.build_check_used(collect_errors);
+        let set_span = if self.track_span {
+            let span_ident = self.span_ident();
+            quote!(#span_ident = Some(span);)
+        } else {
+            quote!()
+        };
+        quote_spanned! { span=>
+            #index => {
+                let span = input.span();
+                #check_used
+                #temp_ident = Some(#expr);
+                #set_span
+            }
+        }
+    }
+    fn build_to_tokens(&self) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        let name = &self.name;
+        let push = self.ty.build_to_tokens(
+            &quote!(#name),
+            &build_call_site_expr(),
+            &self.delimiters,
+            &self.separator,
+        );
+        let call_site = build_call_site_expr();
+        let push_false = if let Some(negated) = &self.negated {
+            quote!(args.push_flag(#negated, #call_site))
+        } else {
+            quote!(args.push_name_value(#name, #call_site, &false))
+        };
+        match self.ty {
+            NamedParamType::Bool if self.is_option => {
+                return quote! {
+                    match #temp_ident {
+                        Some(true) => args.push_flag(#name, #call_site),
+                        Some(false) => #push_false,
+                        None => {}
+                    }
+                };
+            }
+            NamedParamType::Bool if self.default.is_some() => {
+                return quote! {
+                    if *#temp_ident {
+                        args.push_flag(#name, #call_site);
+                    } else {
+                        #push_false;
+                    }
+                };
+            }
+            NamedParamType::Flag if self.default.is_some() => {
+                return quote! {
+                    match #temp_ident.span {
+                        Some(span) => args.push_flag(#name, span),
+                        None => #push_false,
+                    }
+                };
+            }
+            _ => {}
+        }
+        if self.is_repeated {
+            quote!(for value in #temp_ident { #push })
+        } else if self.is_option {
+            quote!(if let Some(value) = #temp_ident { #push })
+        } else {
+            quote!(let value = #temp_ident; #push)
+        }
+    }
+    fn build_let(&self, collect_errors: bool) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        let mut ts = if self.is_repeated {
+            quote!(let mut #temp_ident = Vec::new();)
+        } else if collect_errors {
+            let used_ident = self.used_ident();
+            quote!(let mut #temp_ident = None; let mut #used_ident = false;)
+        } else {
+            quote!(let mut #temp_ident = None;)
+        };
+        if self.track_span {
+            let span_ident = self.span_ident();
+            ts.extend(quote!(let mut #span_ident: Option<::structmeta::helpers::exports::proc_macro2::Span> = None;));
+        }
+        ts
+    }
+    fn build_arm_parse(
+        &self,
+        indexes: &[usize],
+        kind: ArgKind,
+        collect_errors: bool,
+        by_ref: bool,
+    ) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        let place = if by_ref {
+            quote!(*#temp_ident)
+        } else {
+            quote!(#temp_ident)
+        };
         let span = self.info.field.span();
-        let expr = self.ty.build_parse_expr(kind, span);
+        let expr = self
+            .ty
+            .build_parse_expr(kind, span, &self.delimiters, &self.separator);
         let var = kind.to_helper_name_index_variant();
+        let check_used = self.build_check_used(collect_errors);
+        let set_span = if self.track_span {
+            let span_ident = self.span_ident();
+            let span = match self.ty {
+                NamedParamType::Bool | NamedParamType::Flag => quote!((#place).flatten()),
+                _ => quote!(Some(span)),
+            };
+            if by_ref {
+                quote!(*#span_ident = #span;)
+            } else {
+                quote!(#span_ident = #span;)
+            }
+        } else {
+            quote!()
+        };
+        let set_value = if self.is_repeated {
+            quote!(#temp_ident.push(#expr);)
+        } else {
+            quote!(#place = Some(#expr);)
+        };
+        quote_spanned! { span=>
+            ::structmeta::helpers::NameIndex::#var(Ok(#(#indexes)|*)) => {
+                #check_used
+                #set_value
+                #set_span
+            }
+        }
+    }
+    fn build_arm_parse_negated(
+        &self,
+        index: usize,
+        collect_errors: bool,
+        by_ref: bool,
+    ) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        let place = if by_ref {
+            quote!(*#temp_ident)
+        } else {
+            quote!(#temp_ident)
+        };
+        let span = self.info.field.span();
+        let check_used = self.build_check_used(collect_errors);
         quote_spanned! { span=>
-            ::structmeta::helpers::NameIndex::#var(Ok(#index)) => {
+            ::structmeta::helpers::NameIndex::Flag(Ok(#index)) => {
+                #check_used
+                #place = Some(None);
+            }
+        }
+    }
+    fn build_check_used(&self, collect_errors: bool) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        let msg = format!("parameter `{}` specified more than once", self.name);
+        if self.is_repeated {
+            quote!()
+        } else if collect_errors {
+            let used_ident = self.used_ident();
+            quote! {
+                if #used_ident {
+                    return Err(::structmeta::helpers::exports::syn::Error::new(span, #msg));
+                }
+                #used_ident = true;
+            }
+        } else {
+            quote! {
                 if #temp_ident.is_some() {
                     return Err(::structmeta::helpers::exports::syn::Error::new(span, #msg));
                 }
-                #temp_ident = Some(#expr);
             }
         }
     }
-    fn names<'b>(ps: &[&'b Self]) -> Vec<&'b str> {
-        ps.iter().map(|x| x.name.as_str()).collect()
+    fn build_state_ty(&self) -> TokenStream {
+        if self.is_repeated {
+            let ty = self.info.ty;
+            return quote!(Vec<#ty>);
+        }
+        match self.ty {
+            NamedParamType::Bool | NamedParamType::Flag => {
+                quote!(Option<Option<::structmeta::helpers::exports::proc_macro2::Span>>)
+            }
+            _ => {
+                let ty = self.info.ty;
+                quote!(Option<#ty>)
+            }
+        }
+    }
+    fn build_state_init(&self) -> TokenStream {
+        if self.is_repeated {
+            quote!(Vec::new())
+        } else {
+            quote!(None)
+        }
     }
-    fn build_ctor_arg(&self, ctor_args: &mut [TokenStream]) {
+    fn used_ident(&self) -> Ident {
+        format_ident!("_used_{}", self.info.index)
+    }
+    fn span_ident(&self) -> Ident {
+        format_ident!("_span_{}", self.info.index)
+    }
+    fn names(&self) -> impl Iterator<Item = (&str, Span)> {
+        [(self.name.as_str(), self.name_span)].into_iter().chain(
+            self.aliases
+                .iter()
+                .map(|(name, span)| (name.as_str(), *span)),
+        )
+    }
+    fn negated_name(&self) -> Option<(&str, Span)> {
+        Some((self.negated.as_deref()?, self.name_span))
+    }
+    fn push_help_params(&self, name: &str, params: &mut Vec<TokenStream>) {
+        if self.ty.is_flag() {
+            params.push(quote!(::structmeta::helpers::HelpParam::Flag(#name)));
+        }
+        // `name = true` for a flag is already listed as `name`.
+        if self.ty.is_name_value()
+            && !matches!(self.ty, NamedParamType::Bool | NamedParamType::Flag)
+        {
+            params.push(quote!(::structmeta::helpers::HelpParam::NameValue(#name)));
+        }
+        if self.ty.is_name_args() {
+            let open = self.delimiters.chars().next().unwrap();
+            params.push(quote!(::structmeta::helpers::HelpParam::NameArgs(#name, #open)));
+        }
+    }
+    fn names_and_indexes<'b>(ps: &[&'b Self]) -> (Vec<&'b str>, Vec<Vec<usize>>) {
+        let mut names = Vec::new();
+        let mut indexes = Vec::new();
+        for p in ps {
+            let mut p_indexes = Vec::new();
+            for (name, _) in p.names() {
+                p_indexes.push(names.len());
+                names.push(name);
+            }
+            indexes.push(p_indexes);
+        }
+        (names, indexes)
+    }
+    fn build_missing_error(&self, end_span: &TokenStream) -> Option<TokenStream> {
+        if self.is_option || self.is_repeated || self.default.is_some() {
+            return None;
+        }
+        if matches!(self.ty, NamedParamType::Flag | NamedParamType::Bool) {
+            return None;
+        }
+        let msg = if self.ty.is_name_args() {
+            let open = self.delimiters.chars().next().unwrap();
+            let close = match open {
+                '[' => ']',
+                '{' => '}',
+                _ => ')',
+            };
+            format!("missing argument `{}{open}...{close}`", self.name)
+        } else {
+            format!(
+                "missing argument `{} {} ...`",
+                self.name, self.name_value_separator
+            )
+        };
+        Some(quote!(::structmeta::helpers::exports::syn::Error::new(#end_span, #msg)))
+    }
+    fn build_missing_check(&self, end_span: &TokenStream) -> TokenStream {
+        let used_ident = self.used_ident();
+        if let Some(e) = self.build_missing_error(end_span) {
+            quote! {
+                if !#used_ident {
+                    errors.push(#e);
+                }
+            }
+        } else {
+            quote!()
+        }
+    }
+    fn build_ctor_arg(&self, end_span: &TokenStream, ctor_args: &mut [TokenStream]) {
         let temp_ident = &self.info.temp_ident;
-        let value = if self.is_option {
+        let value = if self.is_option && matches!(self.ty, NamedParamType::Bool) {
+            quote!(#temp_ident.map(|span| span.is_some()))
+        } else if self.is_option || self.is_repeated {
             quote!(#temp_ident)
+        } else if let Some(default) = &self.default {
+            let expr = default.to_expr();
+            match self.ty {
+                NamedParamType::Flag => quote! {
+                    match #temp_ident {
+                        Some(span) => ::structmeta::Flag { span },
+                        None => #expr,
+                    }
+                },
+                NamedParamType::Bool => quote! {
+                    match #temp_ident {
+                        Some(span) => span.is_some(),
+                        None => #expr,
+                    }
+                },
+                NamedParamType::Value { .. }
+                | NamedParamType::NameValue { .. }
+                | NamedParamType::NameArgs { .. } => {
+                    quote!(#temp_ident.unwrap_or_else(|| #expr))
+                }
+            }
         } else {
             match self.ty {
-                NamedParamType::Flag => quote!(::structmeta::Flag { span: #temp_ident }),
-                NamedParamType::Bool => quote!(#temp_ident.is_some()),
-                NamedParamType::Value { .. } | NamedParamType::NameValue { .. } => {
-                    let msg = format!("missing argument `{} = ...`", self.name);
-                    quote!(#temp_ident.ok_or_else(|| ::structmeta::helpers::exports::syn::Error::new(::structmeta::helpers::exports::proc_macro2::Span::call_site(), #msg))?)
+                NamedParamType::Flag => {
+                    quote!(::structmeta::Flag { span: #temp_ident.flatten() })
                 }
-                NamedParamType::NameArgs { .. } => {
-                    let msg = format!("missing argument `{}(...)`", self.name);
-                    quote!(#temp_ident.ok_or_else(|| ::structmeta::helpers::exports::syn::Error::new(::structmeta::helpers::exports::proc_macro2::Span::call_site(), #msg))?)
+                NamedParamType::Bool => quote!(#temp_ident.flatten().is_some()),
+                NamedParamType::Value { .. }
+                | NamedParamType::NameValue { .. }
+                | NamedParamType::NameArgs { .. } => {
+                    let e = self.build_missing_error(end_span);
+                    quote!(#temp_ident.ok_or_else(|| #e)?)
                 }
             }
         };
         build_ctor_arg(&self.info, value, ctor_args)
     }
 }
+impl UnknownParam<'_> {
+    fn build_let(&self) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        quote!(let mut #temp_ident = ::structmeta::UnknownArgs::default();)
+    }
+    fn build_push(&self) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        quote!(#temp_ident.args.push(::structmeta::UnknownArg { name, tokens });)
+    }
+    fn build_to_tokens(&self) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        quote!(for arg in #temp_ident { args.push_tokens(arg.tokens.clone()); })
+    }
+    fn build_ctor_arg(&self, ctor_args: &mut [TokenStream]) {
+        let temp_ident = &self.info.temp_ident;
+        build_ctor_arg(&self.info, quote!(#temp_ident), ctor_args)
+    }
+}
+impl FlattenParam<'_> {
+    fn ranges_ident(&self) -> Ident {
+        format_ident!("_ranges_{}", self.info.index)
+    }
+    fn build_let(&self) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        let ty = self.info.ty;
+        quote!(let mut #temp_ident = <#ty as ::structmeta::helpers::Flatten>::new_state();)
+    }
+    fn build_append_names(&self) -> TokenStream {
+        let ranges_ident = self.ranges_ident();
+        let ty = self.info.ty;
+        let name = match &self.info.field.ident {
+            Some(ident) => ident.unraw().to_string(),
+            None => self.info.index.to_string(),
+        };
+        let field = format!("{name}: {}", quote!(#ty));
+        quote!(let #ranges_ident = names.append(#field, <#ty as ::structmeta::helpers::Flatten>::names()?)?;)
+    }
+    fn build_arms_parse(&self, by_ref: bool) -> Vec<TokenStream> {
+        let temp_ident = &self.info.temp_ident;
+        let place = if by_ref {
+            quote!(*#temp_ident)
+        } else {
+            quote!(#temp_ident)
+        };
+        let ranges_ident = self.ranges_ident();
+        let ty = self.info.ty;
+        let span = self.info.span();
+        ArgKind::ALL
+            .into_iter()
+            .map(|kind| {
+                let var = kind.to_helper_name_index_variant();
+                let range = kind.to_flatten_names_field();
+                quote_spanned! { span=>
+                    ::structmeta::helpers::NameIndex::#var(Ok(i)) if #ranges_ident.#range.contains(&i) => {
+                        <#ty as ::structmeta::helpers::Flatten>::parse_arg(
+                            &mut #place,
+                            input,
+                            ::structmeta::helpers::NameIndex::#var(Ok(i - #ranges_ident.#range.start)),
+                            span,
+                        )?;
+                    }
+                }
+            })
+            .collect()
+    }
+    fn build_finish_check(&self, end_span: &TokenStream) -> TokenStream {
+        let temp_ident = &self.info.temp_ident;
+        let ty = self.info.ty;
+        quote! {
+            let #temp_ident = errors.push_result(<#ty as ::structmeta::helpers::Flatten>::finish(#temp_ident, #end_span));
+        }
+    }
+    fn build_ctor_arg(
+        &self,
+        collect_errors: bool,
+        end_span: &TokenStream,
+        ctor_args: &mut [TokenStream],
+    ) {
+        let temp_ident = &self.info.temp_ident;
+        let ty = self.info.ty;
+        let value = if collect_errors {
+            quote!(#temp_ident.unwrap())
+        } else {
+            quote!(<#ty as ::structmeta::helpers::Flatten>::finish(#temp_ident, #end_span)?)
+        };
+        build_ctor_arg(&self.info, value, ctor_args)
+    }
+}
 impl RestParam<'_> {
     fn build_let(&self) -> TokenStream {
         let temp_ident = &self.info.temp_ident;
-        quote!(let mut #temp_ident = ::std::collections::HashMap::new();)
+        let new = match self.kind {
+            RestKind::HashMapString | RestKind::HashMapIdent => {
+                quote!(::std::collections::HashMap::new())
+            }
+            RestKind::BTreeMapString => quote!(::std::collections::BTreeMap::new()),
+            RestKind::VecIdent | RestKind::FlagVecIdent => quote!(::std::vec::Vec::new()),
+            RestKind::RestArgs => quote!(::structmeta::RestArgs::new()),
+            RestKind::FlagHashSetString => quote!(::std::collections::HashSet::new()),
+            RestKind::FlagBTreeSetString => quote!(::std::collections::BTreeSet::new()),
+        };
+        quote!(let mut #temp_ident = #new;)
     }
     fn build_arm_parse(&self, kind: ArgKind) -> TokenStream {
         let temp_ident = &self.info.temp_ident;
         let span = self.info.field.span();
-        let expr = self.ty.build_parse_expr(kind, span);
+        let expr = self
+            .ty
+            .build_parse_expr(kind, span, &self.delimiters, &self.separator);
         let var = kind.to_helper_name_index_variant();
+        let is_duplicate = match self.kind {
+            RestKind::HashMapString | RestKind::BTreeMapString => {
+                quote!(#temp_ident.insert(name.to_string(), #expr).is_some())
+            }
+            RestKind::HashMapIdent | RestKind::RestArgs => {
+                quote!(#temp_ident.insert(name.clone(), #expr).is_some())
+            }
+            RestKind::VecIdent => quote! {{
+                let value = #expr;
+                let is_duplicate = #temp_ident.iter().any(|(n, _)| n == &name);
+                #temp_ident.push((name.clone(), value));
+                is_duplicate
+            }},
+            RestKind::FlagVecIdent => quote! {{
+                let is_duplicate = #temp_ident.contains(&name);
+                #temp_ident.push(name.clone());
+                is_duplicate
+            }},
+            RestKind::FlagHashSetString | RestKind::FlagBTreeSetString => {
+                quote!(!#temp_ident.insert(name.to_string()))
+            }
+        };
         quote_spanned! { span=>
             ::structmeta::helpers::NameIndex::#var(Err(name)) => {
-                if #temp_ident.insert(name.to_string(), #expr).is_some() {
+                if #is_duplicate {
                     return Err(::structmeta::helpers::exports::syn::Error::new(span, format!("parameter `{}` specified more than once", name)));
                 }
             }
@@ -468,9 +1988,20 @@ impl UnnamedParam<'_> {
         let temp_ident = &self.info.temp_ident;
         let span = self.info.field.span();
         let expr = build_parse_expr(self.ty, span);
+        let Some(separator) = &self.separator else {
+            return quote_spanned! { span=>
+                _ => {
+                    #temp_ident.push(#expr);
+                }
+            };
+        };
         quote_spanned! { span=>
             _ => {
                 #temp_ident.push(#expr);
+                while ::structmeta::helpers::peek_arg_separator(input, #separator) {
+                    ::structmeta::helpers::parse_arg_separator(input, #separator)?;
+                    #temp_ident.push(#expr);
+                }
             }
         }
     }
@@ -481,7 +2012,12 @@ impl UnnamedParam<'_> {
                 quote!(#temp_ident)
             }
             (true, false) => {
-                quote!(#temp_ident.unwrap())
+                if let Some(default) = &self.default {
+                    let expr = default.to_expr();
+                    quote!(#temp_ident.unwrap_or_else(|| #expr))
+                } else {
+                    quote!(#temp_ident.unwrap())
+                }
             }
             _ => {
                 unreachable!()
@@ -490,6 +2026,37 @@ impl UnnamedParam<'_> {
         build_ctor_arg(&self.info, value, ctor_args)
     }
 }
+fn build_param_schema(
+    name: Option<&str>,
+    aliases: &[&str],
+    negated_name: Option<&str>,
+    style: TokenStream,
+    occurrence: TokenStream,
+    info: &ParamInfo,
+) -> TokenStream {
+    let name = build_option_str(name);
+    let negated_name = build_option_str(negated_name);
+    let ty = type_to_string(&info.field.ty);
+    let doc = get_doc(&info.field.attrs);
+    quote! {
+        ::structmeta::ParamSchema {
+            name: #name,
+            aliases: &[#(#aliases,)*],
+            negated_name: #negated_name,
+            style: ::structmeta::ParamStyle::#style,
+            occurrence: ::structmeta::Occurrence::#occurrence,
+            ty: #ty,
+            doc: #doc,
+        }
+    }
+}
+fn build_option_str(s: Option<&str>) -> TokenStream {
+    if let Some(s) = s {
+        quote!(Some(#s))
+    } else {
+        quote!(None)
+    }
+}
 fn build_ctor_arg(info: &ParamInfo, value: TokenStream, ctor_args: &mut [TokenStream]) {
     let value = if let Some(ident) = &info.field.ident {
         quote!(#ident : #value)
@@ -502,30 +2069,119 @@ fn build_ctor_arg(info: &ParamInfo, value: TokenStream, ctor_args: &mut [TokenSt
 mod kw {
     use syn::custom_keyword;
 
+    custom_keyword!(alias);
+    custom_keyword!(attr);
+    custom_keyword!(allow_unknown);
+    custom_keyword!(collect_errors);
+    custom_keyword!(conflicts_with);
+    custom_keyword!(default);
+    custom_keyword!(delimiters);
     custom_keyword!(dump);
+    custom_keyword!(flatten);
+    custom_keyword!(group);
+    custom_keyword!(help);
     custom_keyword!(name_filter);
+    custom_keyword!(name_value_separator);
     custom_keyword!(name);
+    custom_keyword!(negatable);
+    custom_keyword!(rename_all);
+    custom_keyword!(repeated);
+    custom_keyword!(required);
+    custom_keyword!(requires);
+    custom_keyword!(rest);
+    custom_keyword!(separator);
+    custom_keyword!(to_tokens);
+    custom_keyword!(unnamed_anywhere);
     custom_keyword!(unnamed);
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone)]
 enum NameFilter {
     None,
     SnakeCase,
+    Lowercase,
+    UpperCamelCase,
+    ScreamingSnakeCase,
+    Fn(Path),
 }
 impl NameFilter {
-    fn to_code(self) -> TokenStream {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "snake_case" => Some(Self::SnakeCase),
+            "lowercase" => Some(Self::Lowercase),
+            "UpperCamelCase" => Some(Self::UpperCamelCase),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnakeCase),
+            _ => None,
+        }
+    }
+    fn to_code(&self) -> TokenStream {
         match self {
             NameFilter::None => quote!(&|_| true),
             NameFilter::SnakeCase => quote!(&::structmeta::helpers::is_snake_case),
+            NameFilter::Lowercase => quote!(&::structmeta::helpers::is_lowercase),
+            NameFilter::UpperCamelCase => quote!(&::structmeta::helpers::is_upper_camel_case),
+            NameFilter::ScreamingSnakeCase => {
+                quote!(&::structmeta::helpers::is_screaming_snake_case)
+            }
+            NameFilter::Fn(path) => quote!(&#path),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RenameAll {
+    Lower,
+    Camel,
+    Pascal,
+    ScreamingSnake,
+}
+impl RenameAll {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "lowercase" => Some(Self::Lower),
+            "camelCase" => Some(Self::Camel),
+            "PascalCase" => Some(Self::Pascal),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnake),
+            _ => None,
+        }
+    }
+    fn apply(self, name: &str) -> String {
+        let words = name.split('_').filter(|w| !w.is_empty());
+        match self {
+            Self::Lower => words.collect(),
+            Self::Camel => {
+                let mut r = String::new();
+                for (i, w) in words.enumerate() {
+                    if i == 0 {
+                        r.push_str(w);
+                    } else {
+                        r.push_str(&to_upper_first(w));
+                    }
+                }
+                r
+            }
+            Self::Pascal => words.map(to_upper_first).collect(),
+            Self::ScreamingSnake => name.to_uppercase(),
         }
     }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Default)]
 struct ArgsForStruct {
     dump: bool,
     name_filter: Option<NameFilter>,
+    rename_all: Option<RenameAll>,
+    groups: Vec<GroupDecl>,
+    allow_unknown: bool,
+    collect_errors: bool,
+    to_tokens: bool,
+    negatable: bool,
+    unnamed_anywhere: bool,
+    help: bool,
+    name_value_separators: Vec<LitStr>,
+    delimiters: Option<LitStr>,
+    separator: Option<LitStr>,
+    attrs: Vec<String>,
 }
 impl ArgsForStruct {
     fn parse_from_attr(&mut self, attr: &Attribute) -> Result<()> {
@@ -533,64 +2189,470 @@ impl ArgsForStruct {
         for arg in args.into_iter() {
             match arg {
                 ArgForStruct::Dump(_) => self.dump = true,
+                ArgForStruct::CollectErrors(_) => self.collect_errors = true,
+                ArgForStruct::AllowUnknown(_) => self.allow_unknown = true,
+                ArgForStruct::ToTokens(_) => self.to_tokens = true,
+                ArgForStruct::Negatable(_) => self.negatable = true,
+                ArgForStruct::UnnamedAnywhere(_) => self.unnamed_anywhere = true,
+                ArgForStruct::Help(_) => self.help = true,
+                ArgForStruct::NameValueSeparator(values) => {
+                    for value in values {
+                        if self
+                            .name_value_separators
+                            .iter()
+                            .any(|s| s.value() == value.value())
+                        {
+                            bail!(value.span(), "`{}` is already exists.", value.value());
+                        }
+                        self.name_value_separators.push(value);
+                    }
+                }
+                ArgForStruct::Attr(values) => {
+                    for (path, span) in values {
+                        if self.attrs.contains(&path) {
+                            bail!(span, "`{}` is already exists.", path);
+                        }
+                        self.attrs.push(path);
+                    }
+                }
+                ArgForStruct::Separator { span, value } => {
+                    if self.separator.is_some() {
+                        bail!(span, "`separator` cannot be specified twice.");
+                    }
+                    self.separator = Some(value);
+                }
+                ArgForStruct::Delimiters { span, value } => {
+                    if self.delimiters.is_some() {
+                        bail!(span, "`delimiters` cannot be specified twice.");
+                    }
+                    self.delimiters = Some(value);
+                }
                 ArgForStruct::NameFilter { span, value } => {
                     if self.name_filter.is_some() {
-                        bail!(span, "`name_filter` cannot be specified twice");
+                        bail!(span, "`name_filter` cannot be specified twice.");
                     }
                     self.name_filter = Some(value);
                 }
+                ArgForStruct::RenameAll { span, value } => {
+                    if self.rename_all.is_some() {
+                        bail!(span, "`rename_all` cannot be specified twice.");
+                    }
+                    self.rename_all = Some(value);
+                }
+                ArgForStruct::Group(value) => {
+                    if self
+                        .groups
+                        .iter()
+                        .any(|g| g.name.value() == value.name.value())
+                    {
+                        bail!(
+                            value.name.span(),
+                            "`{}` is already exists.",
+                            value.name.value()
+                        );
+                    }
+                    self.groups.push(value);
+                }
+            }
+        }
+        Ok(())
+    }
+    fn check_groups_used(&self, used: &BTreeSet<String>) -> Result<()> {
+        for g in &self.groups {
+            if !used.contains(&g.name.value()) {
+                bail!(g.name.span(), "group `{}` has no members.", g.name.value());
             }
         }
         Ok(())
     }
+    fn name_value_separators(&self) -> Vec<String> {
+        if self.name_value_separators.is_empty() {
+            vec!["=".to_string()]
+        } else {
+            self.name_value_separators
+                .iter()
+                .map(|s| s.value())
+                .collect()
+        }
+    }
+    fn separator(&self) -> String {
+        if let Some(s) = &self.separator {
+            s.value()
+        } else {
+            ",".to_string()
+        }
+    }
+    fn delimiters(&self) -> String {
+        if let Some(s) = &self.delimiters {
+            s.value()
+        } else {
+            "(".to_string()
+        }
+    }
     fn name_filter(&self) -> NameFilter {
-        self.name_filter.unwrap_or(NameFilter::None)
+        self.name_filter.clone().unwrap_or(NameFilter::None)
     }
 }
 
 enum ArgForStruct {
     Dump(#[allow(dead_code)] kw::dump),
+    CollectErrors(#[allow(dead_code)] kw::collect_errors),
+    AllowUnknown(#[allow(dead_code)] kw::allow_unknown),
+    ToTokens(#[allow(dead_code)] kw::to_tokens),
+    Negatable(#[allow(dead_code)] kw::negatable),
+    UnnamedAnywhere(#[allow(dead_code)] kw::unnamed_anywhere),
+    Help(#[allow(dead_code)] kw::help),
+    NameValueSeparator(Vec<LitStr>),
+    Attr(Vec<(String, Span)>),
+    Delimiters { span: Span, value: LitStr },
+    Separator { span: Span, value: LitStr },
     NameFilter { span: Span, value: NameFilter },
+    RenameAll { span: Span, value: RenameAll },
+    Group(GroupDecl),
+}
+
+struct GroupDecl {
+    name: LitStr,
+    required: bool,
+}
+impl Parse for GroupDecl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let kw_group: kw::group = input.parse()?;
+        let content;
+        parenthesized!(content in input);
+        let mut name = None;
+        let mut required = false;
+        while !content.is_empty() {
+            if content.peek(kw::name) {
+                let _name: kw::name = content.parse()?;
+                let _eq: Token![=] = content.parse()?;
+                name = Some(content.parse::<LitStr>()?);
+            } else if content.peek(kw::required) {
+                let _required: kw::required = content.parse()?;
+                required = true;
+            } else {
+                return Err(content.error("expected `name = \"...\"` or `required`."));
+            }
+            if !content.is_empty() {
+                let _comma: Token![,] = content.parse()?;
+            }
+        }
+        let Some(name) = name else {
+            bail!(kw_group.span, "`group` requires `name = \"...\"`.")
+        };
+        Ok(Self { name, required })
+    }
 }
 impl Parse for ArgForStruct {
     fn parse(input: ParseStream) -> Result<Self> {
         if input.peek(kw::dump) {
             return Ok(Self::Dump(input.parse()?));
         }
+        if input.peek(kw::collect_errors) {
+            return Ok(Self::CollectErrors(input.parse()?));
+        }
+        if input.peek(kw::allow_unknown) {
+            return Ok(Self::AllowUnknown(input.parse()?));
+        }
+        if input.peek(kw::to_tokens) {
+            return Ok(Self::ToTokens(input.parse()?));
+        }
+        if input.peek(kw::negatable) {
+            return Ok(Self::Negatable(input.parse()?));
+        }
+        if input.peek(kw::unnamed_anywhere) {
+            return Ok(Self::UnnamedAnywhere(input.parse()?));
+        }
+        if input.peek(kw::help) {
+            return Ok(Self::Help(input.parse()?));
+        }
+        if input.peek(kw::name_value_separator) {
+            let _name_value_separator: kw::name_value_separator = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            let mut values = Vec::new();
+            loop {
+                let s: LitStr = input.parse()?;
+                if !matches!(s.value().as_str(), "=" | ":" | "=>") {
+                    bail!(s.span(), "expected \"=\", \":\" or \"=>\"")
+                }
+                values.push(s);
+                if !input.peek(Token![|]) {
+                    break;
+                }
+                let _or: Token![|] = input.parse()?;
+            }
+            return Ok(Self::NameValueSeparator(values));
+        }
+        if input.peek(kw::attr) {
+            let _attr: kw::attr = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            let mut values = Vec::new();
+            loop {
+                let s: LitStr = input.parse()?;
+                let Ok(path) = s.parse_with(Path::parse_mod_style) else {
+                    bail!(s.span(), "expected attribute path")
+                };
+                let path: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+                values.push((path.join("::"), s.span()));
+                if !input.peek(Token![|]) {
+                    break;
+                }
+                let _or: Token![|] = input.parse()?;
+            }
+            return Ok(Self::Attr(values));
+        }
+        if input.peek(kw::separator) {
+            let kw_separator: kw::separator = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            return Ok(Self::Separator {
+                span: kw_separator.span,
+                value: parse_separator(input)?,
+            });
+        }
+        if input.peek(kw::delimiters) {
+            let kw_delimiters: kw::delimiters = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            return Ok(Self::Delimiters {
+                span: kw_delimiters.span,
+                value: parse_delimiters(input)?,
+            });
+        }
         if input.peek(kw::name_filter) {
             let kw_name_filter: kw::name_filter = input.parse()?;
             let _eq: Token![=] = input.parse()?;
-            let s: LitStr = input.parse()?;
-            let value = match s.value().as_str() {
-                "snake_case" => NameFilter::SnakeCase,
-                _ => {
-                    bail!(s.span(), "expected \"snake_case\"")
-                }
+            let value = if input.peek(LitStr) {
+                let s: LitStr = input.parse()?;
+                let Some(value) = NameFilter::from_str(&s.value()) else {
+                    bail!(
+                        s.span(),
+                        "expected \"snake_case\", \"lowercase\", \"UpperCamelCase\", \"SCREAMING_SNAKE_CASE\" or path to function"
+                    )
+                };
+                value
+            } else {
+                NameFilter::Fn(input.parse()?)
             };
             return Ok(Self::NameFilter {
                 span: kw_name_filter.span,
                 value,
             });
         }
-        Err(input.error("usage : #[struct_meta(dump)]"))
+        if input.peek(kw::group) {
+            return Ok(Self::Group(input.parse()?));
+        }
+        if input.peek(kw::rename_all) {
+            let kw_rename_all: kw::rename_all = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            let s: LitStr = input.parse()?;
+            let Some(value) = RenameAll::from_str(&s.value()) else {
+                bail!(
+                    s.span(),
+                    "expected \"lowercase\", \"camelCase\", \"PascalCase\" or \"SCREAMING_SNAKE_CASE\""
+                )
+            };
+            return Ok(Self::RenameAll {
+                span: kw_rename_all.span,
+                value,
+            });
+        }
+        Err(input.error(
+            "expected `dump`, `collect_errors`, `allow_unknown`, `to_tokens`, `negatable`, `unnamed_anywhere`, `help`, `name_value_separator = \"...\"`, `attr = \"...\"`, `separator = \"...\"`, `delimiters = \"...\"`, `name_filter = ...`, `group(...)` or `rename_all = \"...\"`.",
+        ))
+    }
+}
+
+fn parse_separator(input: ParseStream) -> Result<LitStr> {
+    let s: LitStr = input.parse()?;
+    if !matches!(s.value().as_str(), "," | ";" | "|" | "+") {
+        bail!(s.span(), "expected \",\", \";\", \"|\" or \"+\"")
+    }
+    Ok(s)
+}
+fn parse_delimiters(input: ParseStream) -> Result<LitStr> {
+    let s: LitStr = input.parse()?;
+    let value = s.value();
+    if value.is_empty()
+        || value
+            .char_indices()
+            .any(|(i, c)| !"([{".contains(c) || value[..i].contains(c))
+    {
+        bail!(
+            s.span(),
+            "expected a combination of \"(\", \"[\" and \"{\" (e.g. \"([{\")"
+        )
+    }
+    Ok(s)
+}
+
+struct DefaultValue {
+    span: Span,
+    expr: Option<Expr>,
+}
+impl DefaultValue {
+    fn to_expr(&self) -> TokenStream {
+        if let Some(expr) = &self.expr {
+            quote!(#expr)
+        } else {
+            quote_spanned!(self.span=> ::std::default::Default::default())
+        }
     }
 }
 
 struct ArgsForField {
     name: Option<LitStr>,
+    aliases: Vec<LitStr>,
     unnamed: bool,
+    default: Option<DefaultValue>,
+    flatten: Option<Span>,
+    repeated: Option<Span>,
+    rest: Option<Span>,
+    negatable: Option<Span>,
+    delimiters: Option<LitStr>,
+    separator: Option<LitStr>,
+    conflicts_with: Vec<LitStr>,
+    requires: Vec<LitStr>,
+    group: Option<LitStr>,
 }
 impl Parse for ArgsForField {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut name = None;
+        let mut aliases = Vec::new();
         let mut unnamed = false;
+        let mut default = None;
+        let mut flatten = None;
+        let mut repeated = None;
+        let mut rest = None;
+        let mut negatable = None;
+        let mut delimiters = None;
+        let mut separator = None;
+        let mut conflicts_with = Vec::new();
+        let mut requires = Vec::new();
+        let mut group = None;
         for p in Punctuated::<_, Token![,]>::parse_terminated(input)?.into_iter() {
             match p {
                 ArgForField::Name { value, .. } => name = Some(value),
+                ArgForField::ConflictsWith { value, .. } => conflicts_with.push(value),
+                ArgForField::Requires { value, .. } => requires.push(value),
+                ArgForField::Group { kw_group, value } => {
+                    if group.is_some() {
+                        bail!(kw_group.span, "`group` cannot be specified twice.");
+                    }
+                    group = Some(value);
+                }
+                ArgForField::Alias { value, .. } => aliases.push(value),
                 ArgForField::Unnamed { .. } => unnamed = true,
+                ArgForField::Flatten(kw_flatten) => flatten = Some(kw_flatten.span),
+                ArgForField::Repeated(kw_repeated) => repeated = Some(kw_repeated.span),
+                ArgForField::Rest(kw_rest) => rest = Some(kw_rest.span),
+                ArgForField::Negatable(kw_negatable) => negatable = Some(kw_negatable.span),
+                ArgForField::Delimiters {
+                    kw_delimiters,
+                    value,
+                } => {
+                    if delimiters.is_some() {
+                        bail!(
+                            kw_delimiters.span,
+                            "`delimiters` cannot be specified twice."
+                        );
+                    }
+                    delimiters = Some(value);
+                }
+                ArgForField::Separator {
+                    kw_separator,
+                    value,
+                } => {
+                    if separator.is_some() {
+                        bail!(kw_separator.span, "`separator` cannot be specified twice.");
+                    }
+                    separator = Some(value);
+                }
+                ArgForField::Default { span, expr } => {
+                    if default.is_some() {
+                        bail!(span, "`default` cannot be specified twice.");
+                    }
+                    default = Some(DefaultValue { span, expr });
+                }
+            }
+        }
+        Ok(Self {
+            name,
+            aliases,
+            unnamed,
+            default,
+            flatten,
+            repeated,
+            rest,
+            negatable,
+            delimiters,
+            separator,
+            conflicts_with,
+            requires,
+            group,
+        })
+    }
+}
+
+struct ArgsForVariant {
+    name: Option<LitStr>,
+}
+impl Parse for ArgsForVariant {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut name = None;
+        for p in Punctuated::<_, Token![,]>::parse_terminated(input)?.into_iter() {
+            match p {
+                ArgForField::Name { value, .. } => name = Some(value),
+                ArgForField::Alias {
+                    _alias_token: token,
+                    ..
+                } => {
+                    bail!(token.span, "`alias` cannot be used for variant.")
+                }
+                ArgForField::Unnamed {
+                    _unnamed_token: token,
+                } => {
+                    bail!(token.span, "`unnamed` cannot be used for variant.")
+                }
+                ArgForField::Default { span, .. } => {
+                    bail!(span, "`default` cannot be used for variant.")
+                }
+                ArgForField::Flatten(kw_flatten) => {
+                    bail!(kw_flatten.span, "`flatten` cannot be used for variant.")
+                }
+                ArgForField::Repeated(kw_repeated) => {
+                    bail!(kw_repeated.span, "`repeated` cannot be used for variant.")
+                }
+                ArgForField::Rest(kw_rest) => {
+                    bail!(kw_rest.span, "`rest` cannot be used for variant.")
+                }
+                ArgForField::Negatable(kw_negatable) => {
+                    bail!(kw_negatable.span, "`negatable` cannot be used for variant.")
+                }
+                ArgForField::Delimiters { kw_delimiters, .. } => {
+                    bail!(
+                        kw_delimiters.span,
+                        "`delimiters` cannot be used for variant."
+                    )
+                }
+                ArgForField::Separator { kw_separator, .. } => {
+                    bail!(kw_separator.span, "`separator` cannot be used for variant.")
+                }
+                ArgForField::ConflictsWith {
+                    kw_conflicts_with, ..
+                } => {
+                    bail!(
+                        kw_conflicts_with.span,
+                        "`conflicts_with` cannot be used for variant."
+                    )
+                }
+                ArgForField::Requires { kw_requires, .. } => {
+                    bail!(kw_requires.span, "`requires` cannot be used for variant.")
+                }
+                ArgForField::Group { kw_group, .. } => {
+                    bail!(kw_group.span, "`group` cannot be used for variant.")
+                }
             }
         }
-        Ok(Self { name, unnamed })
+        Ok(Self { name })
     }
 }
 
@@ -600,9 +2662,42 @@ enum ArgForField {
         _eq_token: Token![=],
         value: LitStr,
     },
+    Alias {
+        _alias_token: kw::alias,
+        _eq_token: Token![=],
+        value: LitStr,
+    },
     Unnamed {
         _unnamed_token: kw::unnamed,
     },
+    Default {
+        span: Span,
+        expr: Option<Expr>,
+    },
+    Flatten(kw::flatten),
+    Repeated(kw::repeated),
+    Rest(kw::rest),
+    Negatable(kw::negatable),
+    Delimiters {
+        kw_delimiters: kw::delimiters,
+        value: LitStr,
+    },
+    Separator {
+        kw_separator: kw::separator,
+        value: LitStr,
+    },
+    ConflictsWith {
+        kw_conflicts_with: kw::conflicts_with,
+        value: LitStr,
+    },
+    Requires {
+        kw_requires: kw::requires,
+        value: LitStr,
+    },
+    Group {
+        kw_group: kw::group,
+        value: LitStr,
+    },
 }
 impl Parse for ArgForField {
     fn parse(input: ParseStream) -> Result<Self> {
@@ -615,12 +2710,75 @@ impl Parse for ArgForField {
                 _eq_token: eq_token,
                 value,
             })
+        } else if input.peek(kw::alias) && input.peek2(Token![=]) {
+            Ok(Self::Alias {
+                _alias_token: input.parse()?,
+                _eq_token: input.parse()?,
+                value: input.parse()?,
+            })
         } else if input.peek(kw::unnamed) {
             Ok(Self::Unnamed {
                 _unnamed_token: input.parse()?,
             })
+        } else if input.peek(kw::flatten) {
+            Ok(Self::Flatten(input.parse()?))
+        } else if input.peek(kw::repeated) {
+            Ok(Self::Repeated(input.parse()?))
+        } else if input.peek(kw::rest) {
+            Ok(Self::Rest(input.parse()?))
+        } else if input.peek(kw::negatable) {
+            Ok(Self::Negatable(input.parse()?))
+        } else if input.peek(kw::delimiters) && input.peek2(Token![=]) {
+            let kw_delimiters = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            Ok(Self::Delimiters {
+                kw_delimiters,
+                value: parse_delimiters(input)?,
+            })
+        } else if input.peek(kw::separator) && input.peek2(Token![=]) {
+            let kw_separator = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            Ok(Self::Separator {
+                kw_separator,
+                value: parse_separator(input)?,
+            })
+        } else if input.peek(kw::conflicts_with) && input.peek2(Token![=]) {
+            let kw_conflicts_with = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            Ok(Self::ConflictsWith {
+                kw_conflicts_with,
+                value: input.parse()?,
+            })
+        } else if input.peek(kw::requires) && input.peek2(Token![=]) {
+            let kw_requires = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            Ok(Self::Requires {
+                kw_requires,
+                value: input.parse()?,
+            })
+        } else if input.peek(kw::group) && input.peek2(Token![=]) {
+            let kw_group = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            Ok(Self::Group {
+                kw_group,
+                value: input.parse()?,
+            })
+        } else if input.peek(kw::default) {
+            let kw_default: kw::default = input.parse()?;
+            let expr = if input.peek(Token![=]) {
+                let _eq: Token![=] = input.parse()?;
+                Some(input.parse()?)
+            } else {
+                None
+            };
+            Ok(Self::Default {
+                span: kw_default.span,
+                expr,
+            })
         } else {
-            Err(input.error("expected `name = \"...\"` or `unnamed`."))
+            Err(input.error(
+                "expected `name = \"...\"`, `alias = \"...\"`, `unnamed`, `default`, `flatten`, `repeated`, `rest`, `negatable`, `delimiters = \"...\"`, `separator = \"...\"`, `conflicts_with = \"...\"`, `requires = \"...\"` or `group = \"...\"`.",
+            ))
         }
     }
 }
@@ -682,6 +2840,35 @@ impl<'a> NamedParamType<'a> {
             Self::Value { ty, is_vec }
         }
     }
+    fn build_schema_style(&self) -> TokenStream {
+        match self {
+            NamedParamType::Bool | NamedParamType::Flag => quote!(Flag),
+            NamedParamType::Value { is_vec: false, .. } => quote!(NameValue),
+            NamedParamType::Value { is_vec: true, .. } => quote!(NameArgList),
+            NamedParamType::NameValue { is_option, .. } => {
+                if *is_option {
+                    quote!(NameValueOrFlag)
+                } else {
+                    quote!(NameValue)
+                }
+            }
+            NamedParamType::NameArgs {
+                is_option, is_vec, ..
+            } => match (is_vec, is_option) {
+                (false, false) => quote!(NameArgs),
+                (false, true) => quote!(NameArgsOrFlag),
+                (true, false) => quote!(NameArgList),
+                (true, true) => quote!(NameArgListOrFlag),
+            },
+        }
+    }
+    fn is_kind(&self, kind: ArgKind) -> bool {
+        match kind {
+            ArgKind::Flag => self.is_flag(),
+            ArgKind::NameValue => self.is_name_value(),
+            ArgKind::NameArgs => self.is_name_args(),
+        }
+    }
     fn is_flag(&self) -> bool {
         match self {
             NamedParamType::Bool | NamedParamType::Flag => true,
@@ -692,12 +2879,20 @@ impl<'a> NamedParamType<'a> {
     }
     fn is_name_value(&self) -> bool {
         match self {
-            NamedParamType::Bool | NamedParamType::Flag => false,
+            NamedParamType::Bool | NamedParamType::Flag => true,
             NamedParamType::Value { is_vec, .. } => !is_vec,
             NamedParamType::NameValue { .. } => true,
             NamedParamType::NameArgs { .. } => false,
         }
     }
+    fn is_list(&self) -> bool {
+        match self {
+            NamedParamType::Value { is_vec, .. } | NamedParamType::NameArgs { is_vec, .. } => {
+                *is_vec
+            }
+            NamedParamType::Bool | NamedParamType::Flag | NamedParamType::NameValue { .. } => false,
+        }
+    }
     fn is_name_args(&self) -> bool {
         match self {
             NamedParamType::Bool | NamedParamType::Flag => false,
@@ -706,12 +2901,83 @@ impl<'a> NamedParamType<'a> {
             NamedParamType::NameArgs { .. } => true,
         }
     }
-    fn build_parse_expr(&self, kind: ArgKind, span: Span) -> TokenStream {
+    fn build_to_tokens(
+        &self,
+        name: &TokenStream,
+        span: &TokenStream,
+        delimiters: &str,
+        separator: &str,
+    ) -> TokenStream {
+        match self {
+            NamedParamType::Bool => quote!(if *value { args.push_flag(#name, #span); }),
+            NamedParamType::Flag => {
+                quote!(if let Some(span) = value.span { args.push_flag(#name, span); })
+            }
+            NamedParamType::Value { ty, is_vec: false } => {
+                let value = build_to_tokens_value(ty, false, &quote!(value));
+                quote!(args.push_name_value(#name, #span, #value);)
+            }
+            NamedParamType::Value { ty, is_vec: true } => {
+                let value = build_to_tokens_value(ty, true, &quote!(value));
+                let delimiter = delimiters.chars().next().unwrap();
+                quote!(args.push_name_arg_list(#name, #span, &::structmeta::helpers::macro_delimiter(#delimiter), #separator, #value);)
+            }
+            NamedParamType::NameValue { ty, is_option } => {
+                let v = build_to_tokens_value(ty, false, &quote!(v));
+                if *is_option {
+                    quote! {
+                        match &value.value {
+                            Some(v) => args.push_name_value(#name, value.name_span, #v),
+                            None => args.push_flag(#name, value.name_span),
+                        }
+                    }
+                } else {
+                    quote!(let v = &value.value; args.push_name_value(#name, value.name_span, #v);)
+                }
+            }
+            NamedParamType::NameArgs {
+                ty,
+                is_option,
+                is_vec,
+            } => {
+                let v = build_to_tokens_value(ty, *is_vec, &quote!(v));
+                let push = if *is_vec {
+                    quote!(args.push_name_arg_list(#name, value.name_span, &value.delimiter(), #separator, #v))
+                } else {
+                    quote!(args.push_name_args(#name, value.name_span, &value.delimiter(), #v))
+                };
+                if *is_option {
+                    quote! {
+                        match &value.args {
+                            Some(v) => #push,
+                            None => args.push_flag(#name, value.name_span),
+                        }
+                    }
+                } else {
+                    quote!(let v = &value.args; #push;)
+                }
+            }
+        }
+    }
+    fn build_parse_expr(
+        &self,
+        kind: ArgKind,
+        span: Span,
+        delimiters: &str,
+        separator: &str,
+    ) -> TokenStream {
         match self {
-            NamedParamType::Bool | NamedParamType::Flag => quote!(span),
+            NamedParamType::Bool | NamedParamType::Flag => {
+                if kind == ArgKind::Flag {
+                    quote!(Some(span))
+                } else {
+                    quote!(::structmeta::helpers::parse_flag_value(input, span)?)
+                }
+            }
             NamedParamType::Value { ty, is_vec } => {
                 if *is_vec {
-                    build_parse_expr_name_args(ty, *is_vec, span)
+                    let args = build_parse_expr_name_args(ty, *is_vec, span, delimiters, separator);
+                    quote!(#args.1)
                 } else {
                     build_parse_expr(ty, span)
                 }
@@ -734,36 +3000,79 @@ impl<'a> NamedParamType<'a> {
                 is_option,
                 is_vec,
             } => {
-                let args = if kind == ArgKind::Flag && *is_option {
-                    quote!(None)
+                if kind == ArgKind::Flag && *is_option {
+                    return quote!(::structmeta::NameArgs::new(span, None));
+                }
+                let parse = build_parse_expr_name_args(ty, *is_vec, span, delimiters, separator);
+                let args = if *is_option {
+                    quote!(Some(args))
                 } else {
-                    let args = build_parse_expr_name_args(ty, *is_vec, span);
-                    if *is_option {
-                        quote!(Some(#args))
-                    } else {
-                        args
-                    }
+                    quote!(args)
                 };
-                quote!(structmeta::NameArgs { name_span : span, args: #args })
+                quote! {
+                    {
+                        let (delimiter, args) = #parse;
+                        ::structmeta::NameArgs::with_delimiter(span, delimiter, #args)
+                    }
+                }
             }
         }
     }
 }
 
+fn build_to_tokens_value(ty: &Type, is_vec: bool, value: &TokenStream) -> TokenStream {
+    if !is_primitive(ty) {
+        value.clone()
+    } else if is_vec {
+        quote!(&#value.iter().map(::structmeta::helpers::lit_value).collect::<Vec<_>>())
+    } else {
+        quote!(&::structmeta::helpers::lit_value(#value))
+    }
+}
+fn build_call_site_expr() -> TokenStream {
+    quote!(::structmeta::helpers::exports::proc_macro2::Span::call_site())
+}
+
+fn build_parse_call(ty: &Type, input: &TokenStream, span: Span) -> TokenStream {
+    if is_primitive(ty) {
+        quote_spanned!(span=> #input.parse::<::structmeta::LitValue<#ty>>().map(::structmeta::LitValue::into_value))
+    } else {
+        quote_spanned!(span=> #input.parse::<#ty>())
+    }
+}
+fn build_parse_expr_separated(
+    ty: &Type,
+    input: &TokenStream,
+    span: Span,
+    separator: &str,
+) -> TokenStream {
+    if is_primitive(ty) {
+        quote_spanned!(span=> ::structmeta::helpers::parse_separated::<::structmeta::LitValue<#ty>>(#input, #separator)?.into_iter().map(::structmeta::LitValue::into_value).collect())
+    } else {
+        quote_spanned!(span=> ::structmeta::helpers::parse_separated::<#ty>(#input, #separator)?)
+    }
+}
 fn build_parse_expr(ty: &Type, span: Span) -> TokenStream {
-    quote_spanned!(span=> input.parse::<#ty>()?)
+    let parse = build_parse_call(ty, &quote!(input), span);
+    quote_spanned!(span=> #parse?)
 }
-fn build_parse_expr_name_args(ty: &Type, is_vec: bool, span: Span) -> TokenStream {
+fn build_parse_expr_name_args(
+    ty: &Type,
+    is_vec: bool,
+    span: Span,
+    delimiters: &str,
+    separator: &str,
+) -> TokenStream {
     let value = if is_vec {
-        quote_spanned!(span=> ::structmeta::helpers::exports::syn::punctuated::Punctuated::<#ty, ::structmeta::helpers::exports::syn::Token![,]>::parse_terminated(&content)?.into_iter().collect())
+        build_parse_expr_separated(ty, &quote!(&content), span, separator)
     } else {
-        quote_spanned!(span=> content.parse::<#ty>()?)
+        let parse = build_parse_call(ty, &quote!(content), span);
+        quote_spanned!(span=> #parse?)
     };
     quote! {
         {
-            let content;
-            ::structmeta::helpers::exports::syn::parenthesized!(content in input);
-            #value
+            let (delimiter, content) = ::structmeta::helpers::parse_name_args_delimiter(input, #delimiters)?;
+            (delimiter, #value)
         }
     }
 }
@@ -775,6 +3084,15 @@ enum ArgKind {
     NameArgs,
 }
 impl ArgKind {
+    const ALL: [Self; 3] = [Self::Flag, Self::NameValue, Self::NameArgs];
+
+    fn to_flatten_names_field(self) -> TokenStream {
+        match self {
+            Self::Flag => quote!(flag),
+            Self::NameValue => quote!(name_value),
+            Self::NameArgs => quote!(name_args),
+        }
+    }
     fn to_helper_name_index_variant(self) -> TokenStream {
         match self {
             Self::Flag => quote!(Flag),
@@ -784,6 +3102,34 @@ impl ArgKind {
     }
 }
 
+fn to_snake_case(s: &str) -> String {
+    let cs: Vec<_> = s.chars().collect();
+    let mut r = String::new();
+    for (i, &c) in cs.iter().enumerate() {
+        if c.is_uppercase() {
+            // A run of uppercase letters such as `HTTP` in `HTTPGet` is one word.
+            if i != 0
+                && (!cs[i - 1].is_uppercase() || cs.get(i + 1).is_some_and(|c| c.is_lowercase()))
+            {
+                r.push('_');
+            }
+            r.extend(c.to_lowercase());
+        } else {
+            r.push(c);
+        }
+    }
+    r
+}
+
+fn to_upper_first(s: &str) -> String {
+    let mut cs = s.chars();
+    if let Some(c) = cs.next() {
+        c.to_uppercase().chain(cs).collect()
+    } else {
+        String::new()
+    }
+}
+
 fn get_option_element(ty: &Type) -> Option<&Type> {
     get_element(ty, &[&["std", "option"], &["core", "option"]], "Option")
 }
@@ -803,15 +3149,85 @@ fn get_hash_map_element(ty: &Type) -> Option<(&Type, &Type)> {
         "HashMap",
     )
 }
-fn get_hash_map_string_element(ty: &Type) -> Option<&Type> {
-    let (ty_key, ty_value) = get_hash_map_element(ty)?;
-    if is_string(ty_key) {
-        Some(ty_value)
-    } else {
-        None
+fn get_btree_map_element(ty: &Type) -> Option<(&Type, &Type)> {
+    get_element2(
+        ty,
+        &[
+            &["std", "collections"],
+            &["std", "collections", "btree_map"],
+            &["alloc", "collections"],
+            &["alloc", "collections", "btree_map"],
+        ],
+        "BTreeMap",
+    )
+}
+fn get_hash_set_element(ty: &Type) -> Option<&Type> {
+    get_element(
+        ty,
+        &[&["std", "collections"], &["std", "collections", "hash_set"]],
+        "HashSet",
+    )
+}
+fn get_btree_set_element(ty: &Type) -> Option<&Type> {
+    get_element(
+        ty,
+        &[
+            &["std", "collections"],
+            &["std", "collections", "btree_set"],
+            &["alloc", "collections"],
+            &["alloc", "collections", "btree_set"],
+        ],
+        "BTreeSet",
+    )
+}
+fn get_rest_args_element(ty: &Type) -> Option<&Type> {
+    get_element(ty, NS_STRUCTMETA, "RestArgs")
+}
+fn get_rest_element(ty: &Type, is_explicit: bool) -> Option<(RestKind, &Type)> {
+    if let Some((ty_key, ty_value)) = get_hash_map_element(ty) {
+        if is_string(ty_key) {
+            return Some((RestKind::HashMapString, ty_value));
+        }
+        if is_ident(ty_key) {
+            return Some((RestKind::HashMapIdent, ty_value));
+        }
+    }
+    if let Some((ty_key, ty_value)) = get_btree_map_element(ty) {
+        if is_string(ty_key) {
+            return Some((RestKind::BTreeMapString, ty_value));
+        }
+    }
+    if let Some(ty_value) = get_rest_args_element(ty) {
+        return Some((RestKind::RestArgs, ty_value));
     }
+    if let Some(e) = get_vec_element(ty) {
+        if let Type::Tuple(t) = e {
+            if t.elems.len() == 2 && is_ident(&t.elems[0]) {
+                return Some((RestKind::VecIdent, &t.elems[1]));
+            }
+        }
+        if is_explicit && is_ident(e) {
+            return Some((RestKind::FlagVecIdent, ty));
+        }
+    }
+    if get_hash_set_element(ty).is_some_and(is_string) {
+        return Some((RestKind::FlagHashSetString, ty));
+    }
+    if get_btree_set_element(ty).is_some_and(is_string) {
+        return Some((RestKind::FlagBTreeSetString, ty));
+    }
+    None
 }
 
+fn is_primitive(ty: &Type) -> bool {
+    const NAMES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+        "f32", "f64", "bool", "char",
+    ];
+    NAMES.iter().any(|name| is_type(ty, NS_PRIMITIVE, name))
+        || is_string(ty)
+        || is_type(ty, &[&["std", "path"]], "PathBuf")
+}
 fn is_bool(ty: &Type) -> bool {
     is_type(ty, NS_PRIMITIVE, "bool")
 }
@@ -821,6 +3237,12 @@ fn is_flag(ty: &Type) -> bool {
 fn is_string(ty: &Type) -> bool {
     is_type(ty, &[&["std", "string"], &["alloc", "string"]], "String")
 }
+fn is_unknown_args(ty: &Type) -> bool {
+    is_type(ty, NS_STRUCTMETA, "UnknownArgs")
+}
+fn is_ident(ty: &Type) -> bool {
+    is_type(ty, &[&["proc_macro2"], &["syn"]], "Ident")
+}
 
 fn get_element<'a>(ty: &'a Type, ns: &[&[&str]], name: &str) -> Option<&'a Type> {
     if let PathArguments::AngleBracketed(args) = get_arguments_of(ty, ns, name)? {
@@ -852,6 +3274,30 @@ const NS_PRIMITIVE: &[&[&str]] = &[&["std", "primitive"], &["core", "primitive"]
 mod tests {
     use super::*;
     #[test]
+    fn test_rename_all() {
+        let cases = [
+            (RenameAll::Lower, "foo_bar_baz", "foobarbaz"),
+            (RenameAll::Camel, "foo_bar_baz", "fooBarBaz"),
+            (RenameAll::Pascal, "foo_bar_baz", "FooBarBaz"),
+            (RenameAll::ScreamingSnake, "foo_bar_baz", "FOO_BAR_BAZ"),
+            (RenameAll::Camel, "foo", "foo"),
+            (RenameAll::Pascal, "foo", "Foo"),
+        ];
+        for (rename_all, input, expected) in cases {
+            assert_eq!(rename_all.apply(input), expected, "{rename_all:?} {input}");
+        }
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("Get"), "get");
+        assert_eq!(to_snake_case("WebSocket"), "web_socket");
+        assert_eq!(to_snake_case("A1"), "a1");
+        assert_eq!(to_snake_case("HTTPGet"), "http_get");
+        assert_eq!(to_snake_case("GetHTTP"), "get_http");
+        assert_eq!(to_snake_case("ABC"), "abc");
+    }
+    #[test]
     fn test_is_option() {
         assert_eq!(
             get_option_element(&parse_quote!(Option<u8>)),
@@ -1,8 +1,8 @@
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::{ToTokens, quote};
 use syn::{
-    DeriveInput, Path, PathArguments, PathSegment, Result, Token, Type, WherePredicate,
-    punctuated::Punctuated,
+    Attribute, DeriveInput, Expr, ExprLit, Lit, Meta, Path, PathArguments, PathSegment, Result,
+    Token, Type, WherePredicate, punctuated::Punctuated,
 };
 
 macro_rules! bail {
@@ -107,3 +107,60 @@ pub const NS_SYN: &[&[&str]] = &[&["syn"]];
 pub fn is_macro_delimiter(ty: &Type) -> bool {
     is_type(ty, NS_SYN, "MacroDelimiter")
 }
+
+/// Returns the contents of the doc comments in `attrs`.
+pub fn get_doc(attrs: &[Attribute]) -> String {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if let Meta::NameValue(m) = &attr.meta {
+            if let (
+                true,
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }),
+            ) = (m.path.is_ident("doc"), &m.value)
+            {
+                let line = s.value();
+                lines.push(line.strip_prefix(' ').unwrap_or(&line).to_string());
+            }
+        }
+    }
+    lines.join("\n").trim().to_string()
+}
+
+/// Returns the type as it would be written in the source code. (e.g. `Option<Vec<LitStr>>`)
+pub fn type_to_string(ty: &Type) -> String {
+    let mut s = String::new();
+    tokens_to_string(ty.to_token_stream(), &mut s);
+    s
+}
+fn tokens_to_string(tokens: TokenStream, s: &mut String) {
+    let mut last_is_word = false;
+    for tt in tokens {
+        let is_word = matches!(tt, TokenTree::Ident(_) | TokenTree::Literal(_));
+        if is_word && last_is_word {
+            s.push(' ');
+        }
+        last_is_word = is_word;
+        match tt {
+            TokenTree::Group(g) => {
+                let (open, close) = match g.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                s.push_str(open);
+                tokens_to_string(g.stream(), s);
+                s.push_str(close);
+            }
+            TokenTree::Punct(p) => {
+                s.push(p.as_char());
+                if p.as_char() == ',' || p.as_char() == ';' {
+                    s.push(' ');
+                }
+            }
+            tt => s.push_str(&tt.to_string()),
+        }
+    }
+}
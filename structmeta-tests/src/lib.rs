@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use structmeta::{NameArgs, NameValue, Parse, StructMeta};
-use syn::{DeriveInput, LitInt, LitStr, parse, parse_macro_input, parse2};
+use structmeta::{NameArgs, NameValue, Parse, StructMeta, parse_args_spanned};
+use syn::{Attribute, DeriveInput, LitInt, LitStr, parse, parse_macro_input, parse2};
 
 #[derive(StructMeta)]
 struct MyAttr {
@@ -86,6 +86,37 @@ pub fn attr_required_name_value(attr: TokenStream, item: TokenStream) -> TokenSt
     parse_attr::<RequiredNameValue>(attr, item)
 }
 
+#[proc_macro_derive(RequiredNameValue, attributes(required_name_value))]
+pub fn derive_required_name_value(input: TokenStream) -> TokenStream {
+    parse_derive_attrs(input, "required_name_value", |attr| {
+        attr.parse_args::<RequiredNameValue>()
+    })
+}
+
+#[proc_macro_derive(RequiredNameValueSpanned, attributes(required_name_value))]
+pub fn derive_required_name_value_spanned(input: TokenStream) -> TokenStream {
+    parse_derive_attrs(
+        input,
+        "required_name_value",
+        parse_args_spanned::<RequiredNameValue>,
+    )
+}
+
+#[allow(dead_code)]
+#[derive(StructMeta)]
+#[struct_meta(group(name = "mode", required))]
+struct RequiredGroup {
+    #[struct_meta(group = "mode")]
+    get: bool,
+    #[struct_meta(group = "mode")]
+    set: bool,
+}
+
+#[proc_macro_derive(RequiredGroupSpanned, attributes(required_group))]
+pub fn derive_required_group_spanned(input: TokenStream) -> TokenStream {
+    parse_derive_attrs(input, "required_group", parse_args_spanned::<RequiredGroup>)
+}
+
 #[allow(dead_code)]
 #[derive(StructMeta)]
 struct OptionalNameValue {
@@ -150,6 +181,47 @@ pub fn attr_required_name_args_or_flag(attr: TokenStream, item: TokenStream) ->
     parse_attr::<RequiredNameArgsOrFlag>(attr, item)
 }
 
+#[allow(dead_code)]
+#[derive(StructMeta)]
+enum Route {
+    Get(LitStr),
+    Post(LitStr),
+}
+
+#[proc_macro_attribute]
+pub fn attr_enum(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parse_attr::<Route>(attr, item)
+}
+
+#[allow(dead_code)]
+#[derive(StructMeta)]
+struct Conflicts {
+    #[struct_meta(conflicts_with = "rename")]
+    skip: bool,
+    rename: Option<LitStr>,
+}
+
+#[proc_macro_attribute]
+pub fn attr_conflicts(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parse_attr::<Conflicts>(attr, item)
+}
+
+fn parse_derive_attrs<T>(
+    input: TokenStream,
+    path: &str,
+    f: impl Fn(&Attribute) -> syn::Result<T>,
+) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    for attr in &input.attrs {
+        if attr.path().is_ident(path) {
+            if let Err(e) = f(attr) {
+                return e.into_compile_error().into();
+            }
+        }
+    }
+    quote!().into()
+}
+
 fn parse_attr<T: syn::parse::Parse>(attr: TokenStream, item: TokenStream) -> TokenStream {
     match parse::<T>(attr) {
         Ok(_) => item,
@@ -0,0 +1,8 @@
+#[derive(structmeta::StructMeta)]
+struct Example {
+    a: Option<syn::LitInt>,
+    #[struct_meta(alias = "a")]
+    b: Option<syn::LitInt>,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[structmeta_tests::attr_conflicts(rename = "a", skip)]
+fn my_func() {}
+
+fn main() {}
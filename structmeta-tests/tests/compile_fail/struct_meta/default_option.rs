@@ -0,0 +1,7 @@
+#[derive(structmeta::StructMeta)]
+struct Example {
+    #[struct_meta(default)]
+    x: Option<syn::LitInt>,
+}
+
+fn main() {}
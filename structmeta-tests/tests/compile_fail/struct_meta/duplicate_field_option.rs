@@ -0,0 +1,7 @@
+#[derive(structmeta::StructMeta)]
+struct Example {
+    #[struct_meta(separator = ";", separator = ",")]
+    x: Vec<syn::LitInt>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(structmeta::StructMeta)]
+#[struct_meta(separator = ";", separator = ",")]
+struct Example {
+    x: Option<syn::LitInt>,
+}
+
+fn main() {}
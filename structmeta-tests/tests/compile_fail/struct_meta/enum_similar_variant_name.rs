@@ -0,0 +1,4 @@
+#[structmeta_tests::attr_enum(gte, "/x")]
+fn my_func() {}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(structmeta_tests::RequiredGroupSpanned)]
+#[required_group()]
+struct S;
+
+fn main() {}
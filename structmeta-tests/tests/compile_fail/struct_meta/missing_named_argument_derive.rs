@@ -0,0 +1,5 @@
+#[derive(structmeta_tests::RequiredNameValue)]
+#[required_name_value()]
+struct S;
+
+fn main() {}
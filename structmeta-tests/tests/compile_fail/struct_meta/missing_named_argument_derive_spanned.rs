@@ -0,0 +1,5 @@
+#[derive(structmeta_tests::RequiredNameValueSpanned)]
+#[required_name_value()]
+struct S;
+
+fn main() {}
@@ -1,6 +1,7 @@
 #[derive(structmeta::StructMeta)]
+#[struct_meta(unknown)]
 struct Example {
-    flag: Option<bool>,
+    x: Option<syn::LitInt>,
 }
 
 fn main() {}
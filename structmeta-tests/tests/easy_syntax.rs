@@ -0,0 +1,131 @@
+mod test_utils;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use std::{fmt::Debug, path::PathBuf};
+use structmeta::{Keyword, LitValue, StructMeta};
+use syn::{Attribute, parse::Parse, parse_quote};
+use test_utils::*;
+
+#[test]
+fn lit_value_int() {
+    check::<LitValue<u8>, _>(quote!(1), 1);
+    check::<LitValue<u128>, _>(quote!(100), 100);
+    check::<LitValue<usize>, _>(quote!(5), 5);
+    check::<LitValue<i32>, _>(quote!(-3), -3);
+    check::<LitValue<isize>, _>(quote!(7), 7);
+    check_err::<LitValue<u8>>(quote!(256));
+    check_err::<LitValue<u8>>(quote!("1"));
+    check_err::<LitValue<u32>>(quote!(-1));
+}
+
+#[test]
+fn lit_value_float() {
+    check::<LitValue<f64>, _>(quote!(1.5), 1.5);
+    check::<LitValue<f32>, _>(quote!(2), 2.0);
+    check_err::<LitValue<f32>>(quote!(1e100));
+}
+
+#[test]
+fn lit_value_others() {
+    check::<LitValue<bool>, _>(quote!(true), true);
+    check::<LitValue<char>, _>(quote!('a'), 'a');
+    check::<LitValue<String>, _>(quote!("abc"), "abc".to_string());
+    check::<LitValue<PathBuf>, _>(quote!("a/b"), PathBuf::from("a/b"));
+    check::<LitValue<Vec<u8>>, _>(quote!(b"ab"), b"ab".to_vec());
+    check_err::<LitValue<bool>>(quote!(1));
+}
+
+#[test]
+fn lit_value_to_tokens() {
+    let value: LitValue<u32> = parse_quote!(3);
+    assert_eq_ts(value, quote!(3));
+    let value: LitValue<String> = parse_quote!("abc");
+    assert_eq_ts(value, quote!("abc"));
+    let value: LitValue<bool> = parse_quote!(false);
+    assert_eq_ts(value, quote!(false));
+    let value: LitValue<Vec<u8>> = parse_quote!(b"ab");
+    assert_eq_ts(value, quote!(b"ab"));
+}
+
+#[test]
+fn lit_value_to_tokens_non_finite() {
+    let value = LitValue::new(f64::INFINITY, Span::call_site());
+    assert_eq_ts(
+        value,
+        quote!(::core::compile_error! { "`inf` cannot be a float literal" }),
+    );
+    let value = LitValue::new(f32::NAN, Span::call_site());
+    assert_eq_ts(
+        value,
+        quote!(::core::compile_error! { "`NaN` cannot be a float literal" }),
+    );
+}
+
+#[test]
+fn lit_value_accessors() {
+    let value: LitValue<String> = parse_quote!("abc");
+    assert_eq!(value.value(), "abc");
+    assert_eq!(value.len(), 3);
+    assert_eq!(value.into_value(), "abc");
+}
+
+#[test]
+fn keyword() {
+    #[derive(Debug, PartialEq)]
+    enum Mode {
+        Fast,
+        Slow,
+    }
+    impl std::str::FromStr for Mode {
+        type Err = String;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "fast" => Ok(Self::Fast),
+                "slow" => Ok(Self::Slow),
+                _ => Err(format!("unknown mode `{s}`")),
+            }
+        }
+    }
+    impl std::fmt::Display for Mode {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::Fast => write!(f, "fast"),
+                Self::Slow => write!(f, "slow"),
+            }
+        }
+    }
+    check::<Keyword<Mode>, _>(quote!(fast), Mode::Fast);
+    check::<Keyword<Mode>, _>(quote!(slow), Mode::Slow);
+    check_err::<Keyword<Mode>>(quote!(other));
+    let value: Keyword<Mode> = parse_quote!(slow);
+    assert_eq_ts(value, quote!(slow));
+}
+
+#[test]
+fn struct_meta_field() {
+    #[derive(StructMeta)]
+    struct Args {
+        size: LitValue<u32>,
+        name: LitValue<String>,
+        scale: Option<LitValue<f64>>,
+    }
+    let attr: Attribute = parse_quote!(#[attr(size = 4, name = "x")]);
+    let args: Args = attr.parse_args().unwrap();
+    assert_eq!(args.size, 4);
+    assert_eq!(args.name, "x".to_string());
+    assert!(args.scale.is_none());
+}
+
+#[track_caller]
+fn check<T: Parse + PartialEq<V> + Debug, V: Debug>(input: TokenStream, expected: V) {
+    let value: T = syn::parse2(input).unwrap();
+    assert_eq!(value, expected);
+}
+
+#[track_caller]
+fn check_err<T: Parse>(input: TokenStream) {
+    if syn::parse2::<T>(input.clone()).is_ok() {
+        panic!("the parsing did not fail. \ninput : {input}");
+    }
+}
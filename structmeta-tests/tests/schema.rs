@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use structmeta::{
+    ArgsSchema, Flag, NameArgs, NameValue, Occurrence, ParamSchema, ParamStyle, StructMeta,
+};
+use syn::{LitInt, LitStr};
+
+#[test]
+fn named() {
+    #[derive(StructMeta)]
+    #[allow(dead_code)]
+    struct Attr {
+        /// Path of the route.
+        ///
+        /// Must start with `/`.
+        path: LitStr,
+        #[struct_meta(alias = "ignore", negatable)]
+        skip: bool,
+        b: Flag,
+        c: Option<NameValue<Option<LitInt>>>,
+        d: NameArgs<Option<Vec<LitInt>>>,
+        #[struct_meta(default)]
+        e: Vec<LitStr>,
+        f: Vec<NameArgs<LitStr>>,
+    }
+    let schema = Attr::SCHEMA;
+    assert_eq!(
+        schema.params[0],
+        ParamSchema {
+            name: Some("path"),
+            aliases: &[],
+            negated_name: None,
+            style: ParamStyle::NameValue,
+            occurrence: Occurrence::Required,
+            ty: "LitStr",
+            doc: "Path of the route.\n\nMust start with `/`.",
+        }
+    );
+    assert_eq!(
+        schema.params[1],
+        ParamSchema {
+            name: Some("skip"),
+            aliases: &["ignore"],
+            negated_name: Some("no_skip"),
+            style: ParamStyle::Flag,
+            occurrence: Occurrence::Optional,
+            ty: "bool",
+            doc: "",
+        }
+    );
+    let styles: Vec<_> = schema
+        .params
+        .iter()
+        .map(|p| (p.name.unwrap(), p.style, p.occurrence, p.ty))
+        .collect();
+    assert_eq!(
+        styles[2..],
+        [
+            ("b", ParamStyle::Flag, Occurrence::Optional, "Flag"),
+            (
+                "c",
+                ParamStyle::NameValueOrFlag,
+                Occurrence::Optional,
+                "Option<NameValue<Option<LitInt>>>"
+            ),
+            (
+                "d",
+                ParamStyle::NameArgListOrFlag,
+                Occurrence::Required,
+                "NameArgs<Option<Vec<LitInt>>>"
+            ),
+            (
+                "e",
+                ParamStyle::NameArgList,
+                Occurrence::Optional,
+                "Vec<LitStr>"
+            ),
+            (
+                "f",
+                ParamStyle::NameArgs,
+                Occurrence::Repeated,
+                "Vec<NameArgs<LitStr>>"
+            ),
+        ]
+    );
+    assert!(schema.flatten.is_empty());
+    assert!(schema.rest.is_none());
+    assert!(!schema.allow_unknown);
+}
+
+#[test]
+fn unnamed() {
+    #[derive(StructMeta)]
+    #[allow(dead_code)]
+    struct Attr(
+        LitStr,
+        Option<LitInt>,
+        Vec<LitInt>,
+        #[struct_meta(name = "x")] bool,
+    );
+    let params: Vec<_> = Attr::SCHEMA
+        .params
+        .iter()
+        .map(|p| (p.name, p.style, p.occurrence))
+        .collect();
+    assert_eq!(
+        params,
+        [
+            (None, ParamStyle::Unnamed, Occurrence::Required),
+            (None, ParamStyle::Unnamed, Occurrence::Optional),
+            (None, ParamStyle::Unnamed, Occurrence::Variadic),
+            (Some("x"), ParamStyle::Flag, Occurrence::Optional),
+        ]
+    );
+}
+
+#[test]
+fn flatten_and_rest() {
+    #[derive(StructMeta)]
+    #[allow(dead_code)]
+    struct Inner {
+        a: LitInt,
+    }
+    #[derive(StructMeta)]
+    #[allow(dead_code)]
+    struct Attr {
+        #[struct_meta(flatten)]
+        inner: Inner,
+        rest: HashMap<String, LitInt>,
+    }
+    let schema = Attr::SCHEMA;
+    assert!(schema.params.is_empty());
+    assert_eq!(schema.flatten, [Inner::SCHEMA]);
+    let rest = schema.rest.unwrap();
+    assert_eq!(rest.name, None);
+    assert_eq!(rest.style, ParamStyle::NameValue);
+    assert_eq!(rest.occurrence, Occurrence::Repeated);
+}
+
+#[test]
+fn same_schema() {
+    #[derive(StructMeta)]
+    #[allow(dead_code)]
+    struct A {
+        x: LitInt,
+        y: Option<LitStr>,
+    }
+    #[derive(StructMeta)]
+    #[allow(dead_code)]
+    struct B {
+        x: LitInt,
+        y: Option<LitStr>,
+    }
+    assert_eq!(A::SCHEMA, B::SCHEMA);
+}
+
+#[test]
+fn variants() {
+    #[derive(StructMeta)]
+    #[allow(dead_code)]
+    enum Attr {
+        /// Variant A.
+        A { x: LitInt },
+        #[struct_meta(name = "bb")]
+        B,
+    }
+    let schema = Attr::SCHEMA;
+    assert!(schema.params.is_empty());
+    assert_eq!(schema.variants.len(), 2);
+    assert_eq!(schema.variants[0].name, "a");
+    assert_eq!(schema.variants[0].doc, "Variant A.");
+    assert_eq!(schema.variants[0].schema.params[0].name, Some("x"));
+    assert_eq!(schema.variants[1].name, "bb");
+    assert!(schema.variants[1].schema.params.is_empty());
+}
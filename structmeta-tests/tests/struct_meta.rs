@@ -1,7 +1,15 @@
-use proc_macro2::Span;
-use std::{collections::HashMap, fmt::Debug, iter::FromIterator};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Debug,
+    iter::FromIterator,
+};
 use structmeta::*;
-use syn::{Attribute, Expr, LitInt, LitStr, parse::Parse, parse_quote};
+use syn::{
+    Attribute, Expr, Ident, LitInt, LitStr, MacroDelimiter, WherePredicate, parse::Parse,
+    parse_quote,
+};
 
 macro_rules! pq {
     ($($tt:tt)*) =>  { parse_quote!($($tt)*) }
@@ -339,75 +347,1568 @@ fn test_struct_name_args() {
     check(
         pq!(#[attr(abc("xyz"))]),
         Attr {
-            abc: NameArgs {
-                name_span: Span::call_site(),
-                args: pq!("xyz"),
+            abc: NameArgs::new(Span::call_site(), pq!("xyz")),
+        },
+    );
+}
+
+#[test]
+fn test_struct_name_args_or_flag() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        a: NameArgs<Option<LitInt>>,
+    }
+    check(pq!(#[attr(a)]), Attr { a: name_args(None) });
+    check(
+        pq!(#[attr(a(1))]),
+        Attr {
+            a: name_args(Some(pq!(1))),
+        },
+    );
+}
+
+#[test]
+fn test_struct_name_value_or_flag() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        a: NameValue<Option<LitInt>>,
+    }
+    check(
+        pq!(#[attr(a)]),
+        Attr {
+            a: name_value(None),
+        },
+    );
+    check(
+        pq!(#[attr(a = 1)]),
+        Attr {
+            a: name_value(Some(pq!(1))),
+        },
+    );
+}
+
+#[test]
+fn test_struct_map() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        m: HashMap<String, LitStr>,
+    }
+    check_msg(
+        pq!(#[attr()]),
+        Attr {
+            m: HashMap::from_iter(vec![]),
+        },
+        "args 0",
+    );
+    check_msg(
+        pq!(#[attr(abc = "xyz")]),
+        Attr {
+            m: HashMap::from_iter(vec![("abc".into(), pq!("xyz"))]),
+        },
+        "args 1",
+    );
+    check_msg(
+        pq!(#[attr(abc = "xyz", def = "123")]),
+        Attr {
+            m: HashMap::from_iter(vec![("abc".into(), pq!("xyz")), ("def".into(), pq!("123"))]),
+        },
+        "args 2",
+    );
+}
+
+#[test]
+fn test_struct_rest_ordered() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        a: Option<LitInt>,
+        m: Vec<(Ident, LitStr)>,
+    }
+    check(
+        pq!(#[attr(def = "1", a = 2, abc = "3")]),
+        Attr {
+            a: Some(pq!(2)),
+            m: vec![(pq!(def), pq!("1")), (pq!(abc), pq!("3"))],
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(abc = "1", abc = "2")]));
+}
+
+#[test]
+fn test_struct_rest_maps() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct AttrBTreeMap(BTreeMap<String, LitInt>);
+    check(
+        pq!(#[attr(b = 1, a = 2)]),
+        AttrBTreeMap(BTreeMap::from_iter(vec![
+            ("a".into(), pq!(2)),
+            ("b".into(), pq!(1)),
+        ])),
+    );
+
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct AttrHashMapIdent(HashMap<Ident, LitInt>);
+    check(
+        pq!(#[attr(b = 1)]),
+        AttrHashMapIdent(HashMap::from_iter(vec![(pq!(b), pq!(1))])),
+    );
+    check_err::<AttrHashMapIdent>(pq!(#[attr(b = 1, b = 2)]));
+}
+
+#[test]
+fn test_struct_rest_args() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        m: RestArgs<NameArgs<Vec<LitInt>>>,
+    }
+    let attr: Attribute = pq!(#[attr(z(1), a(2, 3))]);
+    let attr: Attr = attr.parse_args().unwrap();
+    let names: Vec<_> = attr.m.names().map(|n| n.to_string()).collect();
+    assert_eq!(names, vec!["z", "a"]);
+    assert_eq!(
+        attr.m.get("a").unwrap().args,
+        vec![pq!(2), pq!(3)] as Vec<LitInt>
+    );
+    assert!(attr.m.get("b").is_none());
+    check_err::<Attr>(pq!(#[attr(a(1), a(2))]));
+}
+
+#[test]
+fn test_struct_rest_flags() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct AttrVec {
+        a: Option<LitInt>,
+        #[struct_meta(rest)]
+        flags: Vec<Ident>,
+    }
+    check(
+        pq!(#[attr(y, a = 1, x)]),
+        AttrVec {
+            a: Some(pq!(1)),
+            flags: vec![pq!(y), pq!(x)],
+        },
+    );
+    check_err::<AttrVec>(pq!(#[attr(x = 1)]));
+    check_err::<AttrVec>(pq!(#[attr(x, x)]));
+
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct AttrHashSet(HashSet<String>);
+    check(
+        pq!(#[attr(y, x)]),
+        AttrHashSet(HashSet::from_iter(vec!["x".into(), "y".into()])),
+    );
+    check_err::<AttrHashSet>(pq!(#[attr(x(1))]));
+}
+
+#[test]
+fn test_allow_unknown() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(allow_unknown)]
+    struct Attr {
+        a: Option<LitInt>,
+        b: bool,
+    }
+    check(
+        pq!(#[attr(x, a = 1, y = "abc" + 1, z(1, 2), b)]),
+        Attr {
+            a: Some(pq!(1)),
+            b: true,
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(a)]));
+    check_err::<Attr>(pq!(#[attr(b = 1)]));
+}
+
+#[test]
+fn test_unknown_args() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        a: Option<LitInt>,
+        unknown: UnknownArgs,
+    }
+    let attr: Attribute = pq!(#[attr(x, a = 1, y = "abc", z(1, 2))]);
+    let attr: Attr = attr.parse_args().unwrap();
+    assert_eq!(attr.a, Some(pq!(1)));
+    let names: Vec<_> = attr.unknown.iter().map(|a| a.name.to_string()).collect();
+    assert_eq!(names, vec!["x", "y", "z"]);
+    assert_eq!(
+        attr.unknown.to_token_stream().to_string(),
+        quote!(x, y = "abc", z(1, 2)).to_string()
+    );
+}
+
+#[test]
+fn test_primitive() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        x: String,
+        a: u32,
+        b: Option<i64>,
+        c: Vec<char>,
+        d: NameValue<f64>,
+        e: NameArgs<Option<String>>,
+        f: Option<std::path::PathBuf>,
+        g: Option<NameValue<bool>>,
+    }
+    let attr: Attribute =
+        pq!(#[attr("abc", a = 1, b = -2, c('x', 'y'), d = 1.5, e("s"), f = "a/b", g = true)]);
+    let attr: Attr = attr.parse_args().unwrap();
+    assert_eq!(attr.x, "abc");
+    assert_eq!(attr.a, 1);
+    assert_eq!(attr.b, Some(-2));
+    assert_eq!(attr.c, vec!['x', 'y']);
+    assert_eq!(attr.d.value, 1.5);
+    assert_eq!(attr.e.args, Some("s".to_string()));
+    assert_eq!(attr.f, Some("a/b".into()));
+    assert!(attr.g.unwrap().value);
+}
+
+#[test]
+fn test_primitive_error() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        a: Option<u8>,
+        b: Option<String>,
+        c: Option<f32>,
+    }
+    check_err_messages::<Attr>(
+        pq!(#[attr(a = "x")]),
+        &["expected integer literal, found string literal"],
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr(b = 1)]),
+        &["expected string literal, found integer literal"],
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr(a = 256)]),
+        &["number too large to fit in target type"],
+    );
+    check_err_messages::<Attr>(pq!(#[attr(a = -1)]), &["expected a non-negative integer"]);
+    check_err_messages::<Attr>(pq!(#[attr(c = 1e100)]), &["number out of range"]);
+}
+
+#[test]
+fn test_spanned() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        a: Spanned<u32>,
+        #[struct_meta(unnamed)]
+        b: Option<Spanned<String>>,
+    }
+    check(
+        pq!(#[attr("x", a = 1)]),
+        Attr {
+            a: Spanned::new(1, Span::call_site()),
+            b: Some(Spanned::new("x".to_string(), Span::call_site())),
+        },
+    );
+}
+
+#[test]
+fn test_to_tokens_primitive() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(to_tokens)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        x: String,
+        a: u32,
+        b: Vec<char>,
+        c: NameValue<Option<f64>>,
+        d: Spanned<i32>,
+    }
+    check_to_tokens::<Attr>(
+        quote!("abc", a = 1, b('x', 'y'), c = 1.5, d = 3),
+        quote!("abc", a = 1, b('x', 'y'), c = 1.5, d = 3),
+    );
+}
+
+#[test]
+fn test_to_tokens() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(to_tokens)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        x: LitStr,
+        a: bool,
+        b: Flag,
+        c: LitInt,
+        d: Option<NameValue<LitInt>>,
+        e: NameValue<Option<LitInt>>,
+        f: NameArgs<Vec<LitInt>>,
+        g: Option<NameArgs<Option<LitStr>>>,
+        h: Vec<LitInt>,
+        i: Option<LitInt>,
+        r#type: Flag,
+    }
+    check_to_tokens::<Attr>(
+        quote!(
+            "abc",
+            a,
+            b,
+            c = 1,
+            d = 2,
+            e,
+            f(3, 4),
+            g("x"),
+            h(5),
+            i = 6,
+            type
+        ),
+        quote!(
+            "abc",
+            a,
+            b,
+            c = 1,
+            d = 2,
+            e,
+            f(3, 4),
+            g("x"),
+            h(5),
+            i = 6,
+            type
+        ),
+    );
+    check_to_tokens::<Attr>(
+        quote!("abc", f(), e = 1, c = 1, h()),
+        quote!("abc", c = 1, e = 1, f(), h()),
+    );
+}
+
+#[test]
+fn test_to_tokens_rest_flatten() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(to_tokens)]
+    struct Inner {
+        a: Option<LitInt>,
+        b: Vec<NameValue<LitInt>>,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(to_tokens)]
+    struct Attr {
+        #[struct_meta(flatten)]
+        inner: Inner,
+        c: Flag,
+        rest: RestArgs<NameArgs<Option<LitInt>>>,
+    }
+    check_to_tokens::<Attr>(quote!(x(1), a = 2, y, c), quote!(a = 2, c, x(1), y));
+    check_to_tokens::<Attr>(quote!(b = 1, b = 2), quote!(b = 1, b = 2));
+    check_to_tokens::<Attr>(quote!(), quote!());
+}
+
+#[test]
+fn test_to_tokens_enum() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(to_tokens)]
+    enum Attr {
+        A,
+        B(LitInt, #[struct_meta(name = "x")] Option<LitInt>),
+        C { y: Flag, unknown: UnknownArgs },
+    }
+    check_to_tokens::<Attr>(quote!(a), quote!(a));
+    check_to_tokens::<Attr>(quote!(b, 1, x = 2), quote!(b, 1, x = 2));
+    check_to_tokens::<Attr>(quote!(c, z(1), y), quote!(c, y, z(1)));
+}
+
+#[test]
+fn test_name_value_separator() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(name_value_separator = ":", to_tokens)]
+    struct Attr {
+        width: LitInt,
+        height: Option<LitInt>,
+    }
+    check(
+        pq!(#[attr(width: 10, height: 20)]),
+        Attr {
+            width: pq!(10),
+            height: Some(pq!(20)),
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(width = 10)]));
+    check_err_messages::<Attr>(
+        pq!(#[attr(width: 10, x: 1)]),
+        &["cannot find parameter `x` in this scope"],
+    );
+    check_to_tokens::<Attr>(quote!(width: 10), quote!(width: 10));
+}
+
+#[test]
+fn test_name_value_separator_multi() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(name_value_separator = "=>" | "=")]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        path: Option<syn::Path>,
+        a: Option<LitInt>,
+        b: Option<LitInt>,
+    }
+    check(
+        pq!(#[attr(a => 1, b = 2)]),
+        Attr {
+            path: None,
+            a: Some(pq!(1)),
+            b: Some(pq!(2)),
+        },
+    );
+    check(
+        pq!(#[attr(x::y, a => 1)]),
+        Attr {
+            path: Some(pq!(x::y)),
+            a: Some(pq!(1)),
+            b: None,
+        },
+    );
+}
+
+#[test]
+fn test_name_value_separator_messages() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(name_value_separator = "=>")]
+    struct Attr {
+        a: LitInt,
+        b: Option<NameArgs<LitInt>>,
+    }
+    check_err_messages::<Attr>(pq!(#[attr(a = 1)]), &["expected `a => ...`"]);
+    check_err_messages::<Attr>(pq!(#[attr()]), &["missing argument `a => ...`"]);
+    check_err_messages::<Attr>(
+        pq!(#[attr(a => 1, b => 2)]),
+        &["expected `b(...)`, found `b => ...`"],
+    );
+}
+
+#[test]
+fn test_name_value_separator_colon_path() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(name_value_separator = ":")]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        ty: Option<syn::Type>,
+        a: Option<LitInt>,
+    }
+    check(
+        pq!(#[attr(std::string::String, a: 1)]),
+        Attr {
+            ty: Some(pq!(std::string::String)),
+            a: Some(pq!(1)),
+        },
+    );
+}
+
+#[test]
+fn test_flag_value() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        a: bool,
+        b: Flag,
+        #[struct_meta(default = true)]
+        c: bool,
+    }
+    check(
+        pq!(#[attr(a = true, b = true, c = false)]),
+        Attr {
+            a: true,
+            b: true.into(),
+            c: false,
+        },
+    );
+    check(
+        pq!(#[attr(a = false, b = false)]),
+        Attr {
+            a: false,
+            b: false.into(),
+            c: true,
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(a = 1)]));
+    check_err::<Attr>(pq!(#[attr(a = false, a)]));
+
+    macro_rules! attr {
+        ($enabled:literal) => {
+            pq!(#[attr(a = $enabled)])
+        };
+    }
+    check(
+        attr!(true),
+        Attr {
+            a: true,
+            b: false.into(),
+            c: true,
+        },
+    );
+}
+
+#[test]
+fn test_flag_value_with_unnamed_expr() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        expr: Option<Expr>,
+        x: bool,
+    }
+    check(
+        pq!(#[attr(y = 1)]),
+        Attr {
+            expr: Some(pq!(y = 1)),
+            x: false,
+        },
+    );
+    check(
+        pq!(#[attr(x = true)]),
+        Attr {
+            expr: None,
+            x: true,
+        },
+    );
+    check(
+        pq!(#[attr(y = 1, x = true)]),
+        Attr {
+            expr: Some(pq!(y = 1)),
+            x: true,
+        },
+    );
+}
+
+#[test]
+fn test_option_bool() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(to_tokens)]
+    struct Attr {
+        a: Option<bool>,
+    }
+    check(pq!(#[attr()]), Attr { a: None });
+    check(pq!(#[attr(a)]), Attr { a: Some(true) });
+    check(pq!(#[attr(a = true)]), Attr { a: Some(true) });
+    check(pq!(#[attr(a = false)]), Attr { a: Some(false) });
+    check_to_tokens::<Attr>(quote!(a = true), quote!(a));
+    check_to_tokens::<Attr>(quote!(a = false), quote!(a = false));
+    check_to_tokens::<Attr>(quote!(), quote!());
+}
+
+#[test]
+fn test_negatable() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(to_tokens)]
+    struct Attr {
+        #[struct_meta(negatable, default = true)]
+        debug: bool,
+        #[struct_meta(negatable)]
+        x: Option<bool>,
+        y: bool,
+    }
+    check(
+        pq!(#[attr()]),
+        Attr {
+            debug: true,
+            x: None,
+            y: false,
+        },
+    );
+    check(
+        pq!(#[attr(no_debug, no_x)]),
+        Attr {
+            debug: false,
+            x: Some(false),
+            y: false,
+        },
+    );
+    check(
+        pq!(#[attr(debug = false, x)]),
+        Attr {
+            debug: false,
+            x: Some(true),
+            y: false,
+        },
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr(debug, no_debug)]),
+        &["parameter `debug` specified more than once"],
+    );
+    check_err::<Attr>(pq!(#[attr(no_y)]));
+    check_err_messages::<Attr>(
+        pq!(#[attr(no_debg)]),
+        &[
+            "cannot find parameter `no_debg` in this scope (help: a parameter with a similar name exists: `no_debug`)",
+        ],
+    );
+    check_to_tokens::<Attr>(quote!(no_debug, no_x), quote!(no_debug, no_x));
+    check_to_tokens::<Attr>(quote!(x, y), quote!(debug, x, y));
+}
+
+#[test]
+fn test_negatable_struct() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(negatable, rename_all = "camelCase")]
+    struct Attr {
+        #[struct_meta(default = true)]
+        use_cache: bool,
+        value: Option<LitInt>,
+    }
+    check(
+        pq!(#[attr(noUseCache)]),
+        Attr {
+            use_cache: false,
+            value: None,
+        },
+    );
+}
+
+#[test]
+fn test_flag_value_constraints() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(conflicts_with = "b")]
+        a: bool,
+        b: bool,
+    }
+    check(pq!(#[attr(a = false, b)]), Attr { a: false, b: true });
+    check_err::<Attr>(pq!(#[attr(a = true, b)]));
+}
+
+#[test]
+fn test_struct_default() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(default)]
+        a: Vec<LitInt>,
+        #[struct_meta(default = pq!("xyz"))]
+        b: LitStr,
+        #[struct_meta(default = default_name_value())]
+        c: NameValue<LitInt>,
+    }
+    fn default_name_value() -> NameValue<LitInt> {
+        name_value(pq!(5))
+    }
+    check(
+        pq!(#[attr()]),
+        Attr {
+            a: vec![],
+            b: pq!("xyz"),
+            c: name_value(pq!(5)),
+        },
+    );
+    check(
+        pq!(#[attr(a(1), b = "abc", c = 2)]),
+        Attr {
+            a: vec![pq!(1)],
+            b: pq!("abc"),
+            c: name_value(pq!(2)),
+        },
+    );
+}
+
+#[test]
+fn test_struct_default_bool() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(default)]
+        a: bool,
+        #[struct_meta(default = true)]
+        b: bool,
+    }
+    check(pq!(#[attr()]), Attr { a: false, b: true });
+    check(pq!(#[attr(a, b)]), Attr { a: true, b: true });
+}
+
+#[test]
+fn test_unnamed_default() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr(LitStr, #[struct_meta(default = pq!(0))] LitInt);
+    check(pq!(#[attr("abc")]), Attr(pq!("abc"), pq!(0)));
+    check(pq!(#[attr("abc", 10)]), Attr(pq!("abc"), pq!(10)));
+}
+
+#[test]
+fn test_unnamed_anywhere() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(unnamed_anywhere)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        x: LitStr,
+        #[struct_meta(unnamed)]
+        y: Option<LitStr>,
+        a: Option<LitInt>,
+        b: bool,
+    }
+    check(
+        pq!(#[attr(a = 1, "x", b, "y")]),
+        Attr {
+            x: pq!("x"),
+            y: Some(pq!("y")),
+            a: Some(pq!(1)),
+            b: true,
+        },
+    );
+    check(
+        pq!(#[attr(b, "x")]),
+        Attr {
+            x: pq!("x"),
+            y: None,
+            a: None,
+            b: true,
+        },
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr(a = 1)]),
+        &["expected least 1 arguments but 0 argument was supplied"],
+    );
+    check_err::<Attr>(pq!(#[attr("x", "y", "z")]));
+}
+
+#[test]
+fn test_unnamed_anywhere_variadic() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(unnamed_anywhere, collect_errors)]
+    struct Attr(LitStr, Vec<LitInt>, #[struct_meta(name = "b")] bool);
+    check(
+        pq!(#[attr(b, "x", 1, 2)]),
+        Attr(pq!("x"), vec![pq!(1), pq!(2)], true),
+    );
+    check(
+        pq!(#[attr("x", 1, b, 2)]),
+        Attr(pq!("x"), vec![pq!(1), pq!(2)], true),
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr(b)]),
+        &["expected least 1 arguments but 0 argument was supplied"],
+    );
+}
+
+#[test]
+fn test_unnamed_or_named() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(unnamed, name = "path")]
+        path: LitStr,
+        #[struct_meta(unnamed, name = "method")]
+        method: Option<LitStr>,
+        x: bool,
+    }
+    check(
+        pq!(#[attr("/x", "GET", x)]),
+        Attr {
+            path: pq!("/x"),
+            method: Some(pq!("GET")),
+            x: true,
+        },
+    );
+    check(
+        pq!(#[attr(path = "/x")]),
+        Attr {
+            path: pq!("/x"),
+            method: None,
+            x: false,
+        },
+    );
+    check(
+        pq!(#[attr("/x", method = "GET")]),
+        Attr {
+            path: pq!("/x"),
+            method: Some(pq!("GET")),
+            x: false,
+        },
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr("/x", path = "/y")]),
+        &["parameter `path` specified more than once"],
+    );
+    check_err_messages::<Attr>(pq!(#[attr(x)]), &["missing argument `path = ...`"]);
+}
+
+#[test]
+fn test_unnamed_or_named_collect_errors() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(collect_errors, unnamed_anywhere)]
+    struct Attr {
+        #[struct_meta(unnamed, name = "path")]
+        path: LitStr,
+        x: Option<LitInt>,
+    }
+    check(
+        pq!(#[attr(x = 1, "/x")]),
+        Attr {
+            path: pq!("/x"),
+            x: Some(pq!(1)),
+        },
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr(path = "/x", "/y")]),
+        &["parameter `path` specified more than once"],
+    );
+}
+
+#[test]
+fn test_to_tokens_unnamed_or_named() {
+    #[derive(StructMeta)]
+    #[struct_meta(to_tokens)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        a: LitInt,
+        #[struct_meta(unnamed, name = "path")]
+        path: LitStr,
+    }
+    check_to_tokens::<Attr>(quote!(1, "/x"), quote!(1, path = "/x"));
+}
+
+#[test]
+fn test_struct_alias() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(alias = "rename_to", alias = "name")]
+        rename: Option<LitStr>,
+        #[struct_meta(name = "skip", alias = "ignore")]
+        x: bool,
+    }
+    check(
+        pq!(#[attr(rename = "a")]),
+        Attr {
+            rename: Some(pq!("a")),
+            x: false,
+        },
+    );
+    check(
+        pq!(#[attr(rename_to = "a", ignore)]),
+        Attr {
+            rename: Some(pq!("a")),
+            x: true,
+        },
+    );
+    check(
+        pq!(#[attr(name = "a", skip)]),
+        Attr {
+            rename: Some(pq!("a")),
+            x: true,
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(rename = "a", rename_to = "b")]));
+    check_err::<Attr>(pq!(#[attr(skip, ignore)]));
+    check_err_messages::<Attr>(
+        pq!(#[attr(renam_to = "a")]),
+        &[
+            "cannot find parameter `renam_to` in this scope (help: a parameter with a similar name exists: `rename_to`)",
+        ],
+    );
+}
+
+#[test]
+fn test_rename_all() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(rename_all = "camelCase")]
+    struct Attr {
+        max_length: Option<LitInt>,
+        #[struct_meta(name = "skip_if")]
+        skip_if_none: bool,
+        r#type: Option<LitStr>,
+    }
+    check(
+        pq!(#[attr(maxLength = 1, skip_if, type = "a")]),
+        Attr {
+            max_length: Some(pq!(1)),
+            skip_if_none: true,
+            r#type: Some(pq!("a")),
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(max_length = 1)]));
+    check_err::<Attr>(pq!(#[attr(skipIf)]));
+}
+
+#[test]
+fn test_rename_all_screaming_snake_case() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(rename_all = "SCREAMING_SNAKE_CASE")]
+    struct Attr {
+        max_length: Option<LitInt>,
+    }
+    check(
+        pq!(#[attr(MAX_LENGTH = 1)]),
+        Attr {
+            max_length: Some(pq!(1)),
+        },
+    );
+}
+
+#[test]
+fn test_enum() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    enum Attr {
+        Get {
+            #[struct_meta(unnamed)]
+            path: LitStr,
+            auth: bool,
+        },
+        WebSocket(LitStr, #[struct_meta(name = "protocol")] Option<LitStr>),
+        #[struct_meta(name = "any")]
+        Other,
+    }
+    check(
+        pq!(#[attr(get, "/x", auth)]),
+        Attr::Get {
+            path: pq!("/x"),
+            auth: true,
+        },
+    );
+    check(
+        pq!(#[attr(web_socket, "/y", protocol = "v2")]),
+        Attr::WebSocket(pq!("/y"), Some(pq!("v2"))),
+    );
+    check(
+        pq!(#[attr(web_socket, "/y")]),
+        Attr::WebSocket(pq!("/y"), None),
+    );
+    check(pq!(#[attr(any)]), Attr::Other);
+    check_err::<Attr>(pq!(#[attr()]));
+    check_err::<Attr>(pq!(#[attr(get)]));
+    check_err::<Attr>(pq!(#[attr(other)]));
+    check_err::<Attr>(pq!(#[attr(any, "/z")]));
+    check_err::<Attr>(pq!(#[attr("/x", get)]));
+}
+
+#[test]
+fn test_collect_errors() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(collect_errors)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        u: LitStr,
+        a: LitInt,
+        b: LitStr,
+        c: Option<LitInt>,
+    }
+    check(
+        pq!(#[attr("u", a = 1, b = "x")]),
+        Attr {
+            u: pq!("u"),
+            a: pq!(1),
+            b: pq!("x"),
+            c: None,
+        },
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr(1, a = "x", x = 1, c = 1 2)]),
+        &[
+            "expected string literal",
+            "expected integer literal",
+            "cannot find parameter `x` in this scope",
+            "expected `,`",
+            "missing argument `b = ...`",
+        ],
+    );
+}
+
+#[test]
+fn test_collect_errors_duplicate() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(collect_errors)]
+    struct Attr {
+        a: Option<LitInt>,
+        b: Option<LitInt>,
+    }
+    check_err_messages::<Attr>(
+        pq!(#[attr(a = 1, a = 2, b = 3, b = 4)]),
+        &[
+            "parameter `a` specified more than once",
+            "parameter `b` specified more than once",
+        ],
+    );
+}
+
+#[test]
+fn test_help() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Common {
+        rename: Option<LitStr>,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(help, name_value_separator = ":")]
+    struct Attr {
+        #[struct_meta(alias = "ignore", negatable)]
+        skip: bool,
+        #[struct_meta(delimiters = "[")]
+        with: Option<NameArgs<LitInt>>,
+        #[struct_meta(flatten)]
+        common: Common,
+    }
+    check_err_messages::<Attr>(
+        pq!(#[attr(x)]),
+        &[
+            "cannot find parameter `x` in this scope",
+            "help: expected one of: `ignore`, `no_skip`, `rename : ...`, `skip`, `with[...]`",
+        ],
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr(wiht[1])]),
+        &[
+            "cannot find parameter `wiht` in this scope (help: a parameter with a similar name exists: `with`)",
+            "help: expected one of: `ignore`, `no_skip`, `rename : ...`, `skip`, `with[...]`",
+        ],
+    );
+}
+
+#[test]
+fn test_help_collect_errors() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(help, collect_errors)]
+    struct Attr {
+        a: Option<LitInt>,
+        b: NameValue<Option<LitStr>>,
+    }
+    check_err_messages::<Attr>(
+        pq!(#[attr(x = 1, b)]),
+        &[
+            "cannot find parameter `x` in this scope",
+            "help: expected one of: `a = ...`, `b`, `b = ...`",
+        ],
+    );
+}
+
+#[test]
+fn test_flatten() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Common {
+        rename: Option<LitStr>,
+        skip: bool,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        path: LitStr,
+        #[struct_meta(flatten)]
+        common: Common,
+        x: Option<NameArgs<LitInt>>,
+    }
+    check(
+        pq!(#[attr("a", skip, x(1), rename = "b")]),
+        Attr {
+            path: pq!("a"),
+            common: Common {
+                rename: Some(pq!("b")),
+                skip: true,
+            },
+            x: Some(name_args(pq!(1))),
+        },
+    );
+    check(
+        pq!(#[attr("a")]),
+        Attr {
+            path: pq!("a"),
+            common: Common {
+                rename: None,
+                skip: false,
+            },
+            x: None,
+        },
+    );
+    check_err::<Attr>(pq!(#[attr("a", skip, skip)]));
+    check_err_messages::<Attr>(
+        pq!(#[attr("a", renam = "b")]),
+        &[
+            "cannot find parameter `renam` in this scope (help: a parameter with a similar name exists: `rename`)",
+        ],
+    );
+}
+
+#[test]
+fn test_flatten_duplicate_name() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Inner {
+        a: LitInt,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(flatten)]
+        inner: Inner,
+        a: Option<LitInt>,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr2 {
+        #[struct_meta(flatten)]
+        inner1: Inner,
+        #[struct_meta(flatten)]
+        inner2: Inner,
+    }
+    check_err_messages::<Attr>(
+        pq!(#[attr(a = 1)]),
+        &["duplicate parameter `a` in flattened field `inner: Inner`"],
+    );
+    check_err_messages::<Attr2>(
+        pq!(#[attr(a = 1)]),
+        &["duplicate parameter `a` in flattened field `inner2: Inner`"],
+    );
+}
+
+#[test]
+fn test_flatten_nested() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Inner {
+        a: LitInt,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Middle {
+        #[struct_meta(flatten)]
+        inner: Inner,
+        b: Option<LitInt>,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr(
+        #[struct_meta(flatten)] Middle,
+        #[struct_meta(name = "c")] bool,
+    );
+    check(
+        pq!(#[attr(c, b = 2, a = 1)]),
+        Attr(
+            Middle {
+                inner: Inner { a: pq!(1) },
+                b: Some(pq!(2)),
             },
+            true,
+        ),
+    );
+    check_err_messages::<Attr>(pq!(#[attr(b = 2)]), &["missing argument `a = ...`"]);
+}
+
+#[test]
+fn test_flatten_collect_errors() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Common {
+        a: LitInt,
+        b: Option<LitStr>,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(collect_errors)]
+    struct Attr {
+        #[struct_meta(flatten)]
+        common: Common,
+        c: LitInt,
+    }
+    check_err_messages::<Attr>(
+        pq!(#[attr(b = 1)]),
+        &[
+            "expected string literal",
+            "missing argument `c = ...`",
+            "missing argument `a = ...`",
+        ],
+    );
+}
+
+#[test]
+fn test_flatten_enum() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Common {
+        auth: bool,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    enum Attr {
+        Get(LitStr, #[struct_meta(flatten)] Common),
+    }
+    check(
+        pq!(#[attr(get, "/x", auth)]),
+        Attr::Get(pq!("/x"), Common { auth: true }),
+    );
+}
+
+#[test]
+fn test_conflicts_with() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(conflicts_with = "rename")]
+        skip: bool,
+        rename: Option<LitStr>,
+    }
+    check(
+        pq!(#[attr(skip)]),
+        Attr {
+            skip: true,
+            rename: None,
+        },
+    );
+    check(
+        pq!(#[attr(rename = "a")]),
+        Attr {
+            skip: false,
+            rename: Some(pq!("a")),
         },
     );
+    check_err_messages::<Attr>(
+        pq!(#[attr(rename = "a", skip)]),
+        &["`skip` cannot be used with `rename`"],
+    );
 }
 
 #[test]
-fn test_struct_name_args_or_flag() {
+fn test_requires() {
     #[derive(StructMeta, PartialEq, Debug)]
     struct Attr {
-        a: NameArgs<Option<LitInt>>,
+        #[struct_meta(requires = "format")]
+        with: Option<LitStr>,
+        format: Option<LitStr>,
     }
-    check(pq!(#[attr(a)]), Attr { a: name_args(None) });
     check(
-        pq!(#[attr(a(1))]),
+        pq!(#[attr(with = "a", format = "b")]),
         Attr {
-            a: name_args(Some(pq!(1))),
+            with: Some(pq!("a")),
+            format: Some(pq!("b")),
+        },
+    );
+    check(
+        pq!(#[attr(format = "b")]),
+        Attr {
+            with: None,
+            format: Some(pq!("b")),
         },
     );
+    check_err_messages::<Attr>(pq!(#[attr(with = "a")]), &["`with` requires `format`"]);
 }
 
 #[test]
-fn test_struct_name_value_or_flag() {
+fn test_group() {
     #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(group(name = "mode", required))]
     struct Attr {
-        a: NameValue<Option<LitInt>>,
+        #[struct_meta(group = "mode")]
+        get: bool,
+        #[struct_meta(group = "mode")]
+        set: bool,
+        #[struct_meta(group = "mode")]
+        both: bool,
     }
     check(
-        pq!(#[attr(a)]),
+        pq!(#[attr(set)]),
         Attr {
-            a: name_value(None),
+            get: false,
+            set: true,
+            both: false,
         },
     );
+    check_err_messages::<Attr>(
+        pq!(#[attr(get, set)]),
+        &["`set` cannot be used with `get` (group `mode`)"],
+    );
+    check_err_messages::<Attr>(
+        pq!(#[attr()]),
+        &["missing one of arguments `get`, `set`, `both` (group `mode`)"],
+    );
+}
+
+#[test]
+fn test_group_not_required() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(group = "mode")]
+        a: Option<LitInt>,
+        #[struct_meta(group = "mode")]
+        b: Option<LitInt>,
+    }
+    check(pq!(#[attr()]), Attr { a: None, b: None });
+    check_err::<Attr>(pq!(#[attr(a = 1, b = 2)]));
+}
+
+#[test]
+fn test_constraints_collect_errors() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(collect_errors)]
+    struct Attr {
+        #[struct_meta(conflicts_with = "b")]
+        a: bool,
+        b: bool,
+        #[struct_meta(requires = "d")]
+        c: bool,
+        d: bool,
+    }
+    check_err_messages::<Attr>(
+        pq!(#[attr(a, b, c)]),
+        &["`a` cannot be used with `b`", "`c` requires `d`"],
+    );
+}
+
+#[test]
+fn test_constraints_flatten() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Common {
+        #[struct_meta(conflicts_with = "rename")]
+        skip: bool,
+        rename: Option<LitStr>,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(flatten)]
+        common: Common,
+    }
     check(
-        pq!(#[attr(a = 1)]),
+        pq!(#[attr(skip)]),
         Attr {
-            a: name_value(Some(pq!(1))),
+            common: Common {
+                skip: true,
+                rename: None,
+            },
         },
     );
+    check_err_messages::<Attr>(
+        pq!(#[attr(skip, rename = "a")]),
+        &["`skip` cannot be used with `rename`"],
+    );
 }
 
 #[test]
-fn test_struct_map() {
+fn test_repeated_name_value() {
     #[derive(StructMeta, PartialEq, Debug)]
     struct Attr {
-        m: HashMap<String, LitStr>,
+        derive: Vec<NameValue<Ident>>,
+        x: Option<LitInt>,
     }
-    check_msg(
+    check(
+        pq!(#[attr(derive = Debug, x = 1, derive = Clone)]),
+        Attr {
+            derive: vec![name_value(pq!(Debug)), name_value(pq!(Clone))],
+            x: Some(pq!(1)),
+        },
+    );
+    check(
         pq!(#[attr()]),
         Attr {
-            m: HashMap::from_iter(vec![]),
+            derive: vec![],
+            x: None,
         },
-        "args 0",
     );
-    check_msg(
-        pq!(#[attr(abc = "xyz")]),
+}
+
+#[test]
+fn test_repeated_name_args() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        field: Vec<NameArgs<Ident>>,
+        bound: Vec<NameArgs<Vec<LitStr>>>,
+    }
+    check(
+        pq!(#[attr(field(a), bound("x", "y"), field(b))]),
         Attr {
-            m: HashMap::from_iter(vec![("abc".into(), pq!("xyz"))]),
+            field: vec![name_args(pq!(a)), name_args(pq!(b))],
+            bound: vec![name_args(vec![pq!("x"), pq!("y")])],
         },
-        "args 1",
     );
-    check_msg(
-        pq!(#[attr(abc = "xyz", def = "123")]),
+}
+
+#[test]
+fn test_name_args_delimiters() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(delimiters = "([{")]
+    struct Attr {
+        fields: NameArgs<Vec<LitInt>>,
+        tags: Vec<LitStr>,
+        x: Option<NameArgs<LitInt>>,
+    }
+    let attr: Attribute = pq!(#[attr(fields{1, 2}, tags["a"], x(3))]);
+    let value: Attr = attr.parse_args().unwrap();
+    assert_eq!(
+        value,
         Attr {
-            m: HashMap::from_iter(vec![("abc".into(), pq!("xyz")), ("def".into(), pq!("123"))]),
+            fields: name_args(vec![pq!(1), pq!(2)]),
+            tags: vec![pq!("a")],
+            x: Some(name_args(pq!(3))),
+        }
+    );
+    assert!(matches!(value.fields.delimiter(), MacroDelimiter::Brace(_)));
+    assert!(matches!(
+        value.x.unwrap().delimiter(),
+        MacroDelimiter::Paren(_)
+    ));
+}
+
+#[test]
+fn test_name_args_delimiters_field() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(delimiters = "[")]
+        tags: Vec<LitStr>,
+        x: Option<NameArgs<LitInt>>,
+    }
+    check(
+        pq!(#[attr(tags["a", "b"], x(1))]),
+        Attr {
+            tags: vec![pq!("a"), pq!("b")],
+            x: Some(name_args(pq!(1))),
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(tags("a"))]));
+    check_err::<Attr>(pq!(#[attr(tags[], x[1])]));
+}
+
+#[test]
+fn test_name_args_delimiters_messages() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(delimiters = "[")]
+        with: NameArgs<Vec<LitInt>>,
+        x: Option<LitInt>,
+    }
+    check_err_messages::<Attr>(
+        pq!(#[attr(with = 1)]),
+        &["expected `with[...]`, found `with = ...`"],
+    );
+    check_err_messages::<Attr>(pq!(#[attr(x = 1)]), &["missing argument `with[...]`"]);
+    check_err_messages::<Attr>(
+        pq!(#[attr(x[1], with[2])]),
+        &["expected `x = ...`, found `x`[...]"],
+    );
+}
+
+#[test]
+fn test_name_args_delimiters_unnamed() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        expr: Option<Expr>,
+        x: Option<NameArgs<LitInt>>,
+    }
+    check(
+        pq!(#[attr(a[0])]),
+        Attr {
+            expr: Some(pq!(a[0])),
+            x: None,
+        },
+    );
+}
+
+#[test]
+fn test_to_tokens_delimiters() {
+    #[derive(StructMeta)]
+    #[struct_meta(to_tokens, delimiters = "{[")]
+    struct Attr {
+        a: NameArgs<Vec<LitInt>>,
+        b: Option<NameArgs<LitStr>>,
+        c: Vec<LitInt>,
+    }
+    check_to_tokens::<Attr>(quote!(a[1, 2], b{"x"}, c[3]), quote!(a[1, 2], b{"x"}, c{3}));
+}
+
+#[test]
+fn test_separator() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(separator = "|")]
+        variants: NameArgs<Vec<Ident>>,
+        #[struct_meta(separator = ";")]
+        bound: Vec<WherePredicate>,
+    }
+    check(
+        pq!(#[attr(variants(A | B | C), bound(T: Clone + Send; U: Debug;))]),
+        Attr {
+            variants: name_args(vec![pq!(A), pq!(B), pq!(C)]),
+            bound: vec![pq!(T: Clone + Send), pq!(U: Debug)],
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(variants(A, B), bound())]));
+}
+
+#[test]
+fn test_separator_unnamed() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(unnamed, separator = "|")]
+        values: Vec<LitStr>,
+        x: Option<LitInt>,
+    }
+    check(
+        pq!(#[attr("a" | "b", x = 1)]),
+        Attr {
+            values: vec![pq!("a"), pq!("b")],
+            x: Some(pq!(1)),
+        },
+    );
+    check(
+        pq!(#[attr("a", "b" | "c")]),
+        Attr {
+            values: vec![pq!("a"), pq!("b"), pq!("c")],
+            x: None,
+        },
+    );
+}
+
+#[test]
+fn test_separator_struct() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(separator = ";")]
+    struct Args {
+        a: LitInt,
+        b: Vec<LitInt>,
+        c: bool,
+    }
+    let args: Args = syn::parse2(quote!(a = 1; b(2, 3); c;)).unwrap();
+    assert_eq!(
+        args,
+        Args {
+            a: pq!(1),
+            b: vec![pq!(2), pq!(3)],
+            c: true,
+        }
+    );
+    assert!(syn::parse2::<Args>(quote!(a = 1, c)).is_err());
+}
+
+#[test]
+fn test_to_tokens_separator() {
+    #[derive(StructMeta)]
+    #[struct_meta(to_tokens, separator = ";")]
+    struct Attr {
+        #[struct_meta(unnamed, separator = "|")]
+        values: Vec<LitStr>,
+        #[struct_meta(separator = "+")]
+        bounds: NameArgs<Vec<Ident>>,
+        c: bool,
+    }
+    check_to_tokens::<Attr>(
+        quote!("a" | "b"; bounds(Clone + Send); c),
+        quote!("a" | "b"; bounds(Clone + Send); c),
+    );
+}
+
+#[test]
+fn test_to_tokens_unknown_args_separator() {
+    #[derive(StructMeta)]
+    #[struct_meta(separator = ";", allow_unknown, to_tokens)]
+    struct Attr {
+        a: bool,
+        unknown: UnknownArgs,
+        b: bool,
+    }
+    check_to_tokens::<Attr>(quote!(a; x = 1; y(2, 3); b), quote!(a; x = 1; y(2, 3); b));
+}
+
+#[test]
+fn test_repeated_attribute() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(repeated)]
+        derive: Vec<Ident>,
+        #[struct_meta(repeated)]
+        bound: Vec<Vec<LitStr>>,
+    }
+    check(
+        pq!(#[attr(derive = Debug, bound("a"), derive = Clone, bound("b", "c"))]),
+        Attr {
+            derive: vec![pq!(Debug), pq!(Clone)],
+            bound: vec![vec![pq!("a")], vec![pq!("b"), pq!("c")]],
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(derive(Debug))]));
+}
+
+#[test]
+fn test_repeated_flatten() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Common {
+        derive: Vec<NameValue<Ident>>,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    struct Attr {
+        #[struct_meta(flatten)]
+        common: Common,
+    }
+    check(
+        pq!(#[attr(derive = Debug, derive = Clone)]),
+        Attr {
+            common: Common {
+                derive: vec![name_value(pq!(Debug)), name_value(pq!(Clone))],
+            },
         },
-        "args 2",
     );
 }
 
@@ -457,6 +1958,70 @@ fn name_filter() {
     );
 }
 
+#[test]
+fn name_filter_upper_camel_case() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(name_filter = "snake_case")]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        ty: Option<Ident>,
+        #[struct_meta(name = "X")]
+        x: bool,
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(name_filter = "UpperCamelCase")]
+    struct AttrUpper {
+        #[struct_meta(unnamed)]
+        ty: Option<Ident>,
+        #[struct_meta(name = "X")]
+        x: bool,
+    }
+    check(
+        pq!(#[attr(X)]),
+        Attr {
+            ty: Some(pq!(X)),
+            x: false,
+        },
+    );
+    check(pq!(#[attr(X)]), AttrUpper { ty: None, x: true });
+    check(
+        pq!(#[attr(my_type)]),
+        AttrUpper {
+            ty: Some(pq!(my_type)),
+            x: false,
+        },
+    );
+}
+
+#[test]
+fn name_filter_fn() {
+    fn not_underscore(s: &str) -> bool {
+        !s.starts_with('_')
+    }
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(name_filter = not_underscore)]
+    struct Attr {
+        #[struct_meta(unnamed)]
+        value: Option<Ident>,
+        x: bool,
+    }
+    check(
+        pq!(#[attr(_x)]),
+        Attr {
+            value: Some(pq!(_x)),
+            x: false,
+        },
+    );
+    check(
+        pq!(#[attr(x)]),
+        Attr {
+            value: None,
+            x: true,
+        },
+    );
+    check_err::<Attr>(pq!(#[attr(y)]));
+}
+
 #[test]
 fn test_expr_or_name_value() {
     #[derive(StructMeta, PartialEq, Debug)]
@@ -557,10 +2122,67 @@ fn name_value<T>(value: T) -> NameValue<T> {
     }
 }
 fn name_args<T>(args: T) -> NameArgs<T> {
-    NameArgs {
-        args,
-        name_span: Span::call_site(),
+    NameArgs::new(Span::call_site(), args)
+}
+
+#[test]
+fn test_from_attrs() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(attr = "my_attr" | "my::attr")]
+    struct Attr {
+        a: Option<LitInt>,
+        b: Option<LitStr>,
+        c: bool,
+    }
+    let attrs: Vec<Attribute> = vec![
+        pq!(#[doc = "x"]),
+        pq!(#[my_attr(a = 1,)]),
+        pq!(#[other(a = 2)]),
+        pq!(#[my::attr(b = "x")]),
+        pq!(#[my_attr]),
+        pq!(#[my_attr(c)]),
+    ];
+    assert_eq!(
+        Attr::from_attrs(&attrs).unwrap(),
+        Some(Attr {
+            a: Some(pq!(1)),
+            b: Some(pq!("x")),
+            c: true,
+        })
+    );
+    assert_eq!(Attr::from_attrs(&[pq!(#[other(a = 1)])]).unwrap(), None);
+    assert_eq!(
+        Attr::from_attrs(&[pq!(#[my_attr])]).unwrap(),
+        Some(Attr {
+            a: None,
+            b: None,
+            c: false,
+        })
+    );
+
+    let e = Attr::from_attrs(&[pq!(#[my_attr(a = 1)]), pq!(#[my::attr(a = 2)])]).unwrap_err();
+    assert_eq!(e.to_string(), "parameter `a` specified more than once");
+    let e = Attr::from_attrs(&[pq!(#[my_attr = 1])]).unwrap_err();
+    assert!(e.to_string().starts_with("expected `(`"), "{e}");
+    let e = Attr::from_attrs_required(&[pq!(#[attr(a = 1)])]).unwrap_err();
+    assert_eq!(e.to_string(), "missing attribute `#[my_attr(...)]`");
+}
+
+#[test]
+fn test_from_attrs_separator() {
+    #[derive(StructMeta, PartialEq, Debug)]
+    #[struct_meta(attr = "my_attr", separator = ";")]
+    struct Attr {
+        a: Option<LitInt>,
+        b: Option<LitInt>,
     }
+    assert_eq!(
+        Attr::from_attrs_required(&[pq!(#[my_attr(a = 1)]), pq!(#[my_attr(b = 2)])]).unwrap(),
+        Attr {
+            a: Some(pq!(1)),
+            b: Some(pq!(2)),
+        }
+    );
 }
 
 #[track_caller]
@@ -579,9 +2201,28 @@ fn check_msg<T: Parse + PartialEq + Debug>(input: Attribute, expected: T, msg: &
     }
 }
 
+#[track_caller]
+fn check_to_tokens<T: Parse + ToTokens>(input: TokenStream, expected: TokenStream) {
+    let value: T = syn::parse2(input).unwrap();
+    assert_eq!(value.to_token_stream().to_string(), expected.to_string());
+}
+
 #[track_caller]
 fn check_err<T: Parse + PartialEq + Debug>(input: Attribute) {
     if let Ok(value) = input.parse_args::<T>() {
         panic!("the parsing did not fail. \ninput : {input:?}\n value : {value:?}");
     }
 }
+
+#[track_caller]
+fn check_err_messages<T: Parse + PartialEq + Debug>(input: Attribute, expected: &[&str]) {
+    match input.parse_args::<T>() {
+        Ok(value) => {
+            panic!("the parsing did not fail. \ninput : {input:?}\n value : {value:?}");
+        }
+        Err(e) => {
+            let messages: Vec<_> = e.into_iter().map(|e| e.to_string()).collect();
+            assert_eq!(messages, expected);
+        }
+    }
+}
@@ -1,6 +1,11 @@
-use std::ops::{BitOr, BitOrAssign};
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::{BitOr, BitOrAssign},
+};
 
-use proc_macro2::Span;
+use proc_macro2::{Delimiter, Ident, Span, TokenStream, extra::DelimSpan};
+use quote::ToTokens;
+use syn::{MacroDelimiter, Token, token};
 /// `name` style attribute argument.
 ///
 /// See [`#[derive(StructMeta)]`](macro@crate::StructMeta) documentation for details.
@@ -56,14 +61,205 @@ impl<T: PartialEq> PartialEq for NameValue<T> {
 
 /// `name(value)` style attribute argument.
 ///
+/// The delimiter that surrounds `args` is available from [`NameArgs::delimiter`]. It is `(` if `args` is omitted.
+///
+/// Use [`NameArgs::new`] or [`NameArgs::with_delimiter`] to construct a value.
+///
 /// See [`#[derive(StructMeta)]`](macro@crate::StructMeta) documentation for details.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone)]
 pub struct NameArgs<T> {
     pub name_span: Span,
     pub args: T,
+    delimiter: Delimiter,
+    delim_span: DelimSpan,
+}
+impl<T> NameArgs<T> {
+    pub fn new(name_span: Span, args: T) -> Self {
+        Self::with_delimiter(
+            name_span,
+            MacroDelimiter::Paren(token::Paren(name_span)),
+            args,
+        )
+    }
+    pub fn with_delimiter(name_span: Span, delimiter: MacroDelimiter, args: T) -> Self {
+        let (delimiter, delim_span) = match delimiter {
+            MacroDelimiter::Paren(p) => (Delimiter::Parenthesis, p.span),
+            MacroDelimiter::Brace(b) => (Delimiter::Brace, b.span),
+            MacroDelimiter::Bracket(b) => (Delimiter::Bracket, b.span),
+        };
+        Self {
+            name_span,
+            args,
+            delimiter,
+            delim_span,
+        }
+    }
+    pub fn delimiter(&self) -> MacroDelimiter {
+        match self.delimiter {
+            Delimiter::Brace => MacroDelimiter::Brace(token::Brace(self.delim_span)),
+            Delimiter::Bracket => MacroDelimiter::Bracket(token::Bracket(self.delim_span)),
+            _ => MacroDelimiter::Paren(token::Paren(self.delim_span)),
+        }
+    }
+}
+impl<T: Debug> Debug for NameArgs<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let delimiter = match self.delimiter {
+            Delimiter::Brace => "{}",
+            Delimiter::Bracket => "[]",
+            _ => "()",
+        };
+        f.debug_struct("NameArgs")
+            .field("name_span", &self.name_span)
+            .field("delimiter", &delimiter)
+            .field("args", &self.args)
+            .finish()
+    }
 }
 impl<T: PartialEq> PartialEq for NameArgs<T> {
     fn eq(&self, other: &Self) -> bool {
         self.args == other.args
     }
 }
+
+/// Rest named arguments that keep the order of appearance and the spans of the names.
+///
+/// See [`#[derive(StructMeta)]`](macro@crate::StructMeta) documentation for details.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestArgs<T> {
+    items: Vec<(Ident, T)>,
+}
+impl<T> RestArgs<T> {
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    pub fn get(&self, name: &str) -> Option<&T> {
+        self.get_key_value(name).map(|(_, value)| value)
+    }
+    pub fn get_key_value(&self, name: &str) -> Option<(&Ident, &T)> {
+        self.items
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(key, value)| (key, value))
+    }
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+    /// Inserts an argument.
+    ///
+    /// If the name already exists, the value is replaced and the old value is returned.
+    /// The position of the argument is not changed in that case.
+    pub fn insert(&mut self, name: Ident, value: T) -> Option<T> {
+        if let Some((_, v)) = self.items.iter_mut().find(|(key, _)| key == &name) {
+            Some(std::mem::replace(v, value))
+        } else {
+            self.items.push((name, value));
+            None
+        }
+    }
+    pub fn names(&self) -> impl Iterator<Item = &Ident> {
+        self.items.iter().map(|(key, _)| key)
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, (Ident, T)> {
+        self.items.iter()
+    }
+}
+impl<T> Default for RestArgs<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> IntoIterator for RestArgs<T> {
+    type Item = (Ident, T);
+    type IntoIter = std::vec::IntoIter<(Ident, T)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a RestArgs<T> {
+    type Item = &'a (Ident, T);
+    type IntoIter = std::slice::Iter<'a, (Ident, T)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+impl<T> FromIterator<(Ident, T)> for RestArgs<T> {
+    fn from_iter<I: IntoIterator<Item = (Ident, T)>>(iter: I) -> Self {
+        let mut this = Self::new();
+        for (name, value) in iter {
+            this.insert(name, value);
+        }
+        this
+    }
+}
+
+/// Arguments that are not associated with any parameter.
+///
+/// [`ToTokens`] separates the arguments with `,`.
+/// In the output of `#[struct_meta(to_tokens)]`, the separator of the struct is used instead.
+///
+/// See [`#[derive(StructMeta)]`](macro@crate::StructMeta) documentation for details.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UnknownArgs {
+    pub args: Vec<UnknownArg>,
+}
+impl UnknownArgs {
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, UnknownArg> {
+        self.args.iter()
+    }
+}
+impl ToTokens for UnknownArgs {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for (i, arg) in self.args.iter().enumerate() {
+            if i != 0 {
+                <Token![,]>::default().to_tokens(tokens);
+            }
+            arg.to_tokens(tokens);
+        }
+    }
+}
+impl IntoIterator for UnknownArgs {
+    type Item = UnknownArg;
+    type IntoIter = std::vec::IntoIter<UnknownArg>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.args.into_iter()
+    }
+}
+impl<'a> IntoIterator for &'a UnknownArgs {
+    type Item = &'a UnknownArg;
+    type IntoIter = std::slice::Iter<'a, UnknownArg>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.args.iter()
+    }
+}
+
+/// An argument that is not associated with any parameter.
+///
+/// `tokens` contains the whole argument including the name.
+#[derive(Clone, Debug)]
+pub struct UnknownArg {
+    pub name: Ident,
+    pub tokens: TokenStream,
+}
+impl PartialEq for UnknownArg {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.tokens.to_string() == other.tokens.to_string()
+    }
+}
+impl ToTokens for UnknownArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.tokens.clone());
+    }
+}
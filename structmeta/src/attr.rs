@@ -0,0 +1,51 @@
+use proc_macro2::{Delimiter, Group, Span, TokenStream};
+use quote::ToTokens;
+use syn::{
+    Attribute, Meta, Path, Result, parenthesized,
+    parse::{Parse, ParseStream, Parser},
+};
+
+/// Same as [`syn::Attribute::parse_args`], but errors at the end of the arguments are reported at the path of the attribute.
+///
+/// With [`syn::Attribute::parse_args`], errors such as a missing argument point to the closing delimiter.
+///
+/// ```rust
+/// use structmeta::{StructMeta, parse_args_spanned};
+/// use syn::{parse_quote, Attribute, LitStr};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     name: LitStr,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(name = "a")]);
+/// let args: Args = parse_args_spanned(&attr)?;
+/// assert_eq!(args.name.value(), "a");
+/// # syn::Result::Ok(())
+/// ```
+pub fn parse_args_spanned<T: Parse>(attr: &Attribute) -> Result<T> {
+    let Meta::List(list) = &attr.meta else {
+        return attr.parse_args();
+    };
+    parse_with_end_span(list.tokens.clone(), path_span(attr.path()))
+}
+
+pub(crate) fn path_span(path: &Path) -> Span {
+    path.segments.last().unwrap().ident.span()
+}
+
+/// Parse `tokens`, reporting errors at the end of `tokens` at `span`.
+pub(crate) fn parse_with_end_span<T: Parse>(tokens: TokenStream, span: Span) -> Result<T> {
+    let mut group = Group::new(Delimiter::Parenthesis, tokens);
+    group.set_span(span);
+    let parser = |input: ParseStream| {
+        let content;
+        parenthesized!(content in input);
+        let value = content.parse()?;
+        if !content.is_empty() {
+            return Err(content.error("unexpected token"));
+        }
+        Ok(value)
+    };
+    parser.parse2(group.into_token_stream())
+}
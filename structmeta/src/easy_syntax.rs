@@ -1,15 +1,31 @@
-use proc_macro2::{Ident, Span};
-use std::{fmt::Display, str::FromStr};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::ToTokens;
+use std::{fmt::Display, ops::Deref, path::PathBuf, str::FromStr};
 use syn::{
+    Error, Lit, LitBool, LitByteStr, LitChar, LitFloat, LitInt, LitStr, Result,
     ext::IdentExt,
     parse::{Parse, ParseStream},
-    Error, LitFloat, LitInt, LitStr, Result,
 };
 
+/// Identifier converted to a value using [`FromStr`].
+///
+/// ```rust
+/// use structmeta::Keyword;
+/// use syn::parse_quote;
+///
+/// let k: Keyword<bool> = parse_quote!(true);
+/// assert!(*k);
+/// ```
+#[derive(Copy, Clone, Debug)]
 pub struct Keyword<T> {
     pub span: Span,
     pub value: T,
 }
+impl<T> Keyword<T> {
+    pub fn new(value: T, span: Span) -> Self {
+        Self { span, value }
+    }
+}
 impl<T: FromStr> Parse for Keyword<T>
 where
     T::Err: Display,
@@ -23,12 +39,81 @@ where
         }
     }
 }
+impl<T: Display> ToTokens for Keyword<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        Ident::new(&self.value.to_string(), self.span).to_tokens(tokens);
+    }
+}
+impl<T> Deref for Keyword<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+impl<T: PartialEq> PartialEq for Keyword<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+impl<T: PartialEq> PartialEq<T> for Keyword<T> {
+    fn eq(&self, other: &T) -> bool {
+        self.value.eq(other)
+    }
+}
 
+/// Literal converted to a Rust value.
+///
+/// | type                    | literal                          |
+/// | ----------------------- | -------------------------------- |
+/// | `u8` .. `u128`, `usize` | integer literal                  |
+/// | `i8` .. `i128`, `isize` | integer literal                  |
+/// | `f32`, `f64`            | float literal or integer literal |
+/// | `bool`                  | `true` or `false`                |
+/// | `char`                  | character literal                |
+/// | `String`, `PathBuf`     | string literal                   |
+/// | `Vec<u8>`               | byte string literal              |
+///
+/// ```rust
+/// use structmeta::{LitValue, StructMeta};
+/// use syn::{parse_quote, Attribute};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     size: LitValue<u32>,
+///     name: LitValue<String>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(size = 4, name = "x")]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(*args.size, 4);
+/// assert_eq!(args.name.as_str(), "x");
+/// # syn::Result::Ok(())
+/// ```
 #[derive(Copy, Clone, Debug)]
 pub struct LitValue<T> {
     span: Span,
     value: T,
 }
+impl<T> LitValue<T> {
+    pub fn new(value: T, span: Span) -> Self {
+        Self { span, value }
+    }
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+    pub fn into_value(self) -> T {
+        self.value
+    }
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+impl<T> Deref for LitValue<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
 impl<T: PartialEq> PartialEq for LitValue<T> {
     fn eq(&self, other: &Self) -> bool {
         self.value == other.value
@@ -40,50 +125,172 @@ impl<T: PartialEq> PartialEq<T> for LitValue<T> {
     }
 }
 
-macro_rules! impl_parse_lit_value_int {
-    ($($ty:ty),*) => {
+fn parse_lit<T: Parse>(input: ParseStream, expected: &str) -> Result<T> {
+    let fork = input.fork();
+    match fork.parse::<T>() {
+        Ok(_) => input.parse(),
+        Err(e) => {
+            let found = match input.fork().parse::<Lit>() {
+                Ok(Lit::Str(_)) => "string literal",
+                Ok(Lit::ByteStr(_)) => "byte string literal",
+                Ok(Lit::CStr(_)) => "C string literal",
+                Ok(Lit::Byte(_)) => "byte literal",
+                Ok(Lit::Char(_)) => "character literal",
+                Ok(Lit::Int(_)) => "integer literal",
+                Ok(Lit::Float(_)) => "float literal",
+                Ok(Lit::Bool(_)) => "boolean literal",
+                _ => return Err(e),
+            };
+            Err(input.error(format!("expected {expected}, found {found}")))
+        }
+    }
+}
+fn literal_to_tokens(mut lit: Literal, span: Span, tokens: &mut TokenStream) {
+    lit.set_span(span);
+    lit.to_tokens(tokens);
+}
+
+macro_rules! impl_lit_value_int {
+    ($($ty:ty => $unsuffixed:ident),*) => {
         $(
         impl Parse for LitValue<$ty> {
             fn parse(input: ParseStream) -> Result<Self> {
-                let lit = input.parse::<LitInt>()?;
+                let lit = parse_lit::<LitInt>(input, "integer literal")?;
+                if <$ty>::MIN == 0 && lit.base10_digits().starts_with('-') {
+                    return Err(Error::new(lit.span(), "expected a non-negative integer"));
+                }
                 Ok(Self {
                     span: lit.span(),
                     value: lit.base10_parse()?,
                 })
             }
         }
+        impl ToTokens for LitValue<$ty> {
+            fn to_tokens(&self, tokens: &mut TokenStream) {
+                literal_to_tokens(Literal::$unsuffixed(self.value), self.span, tokens);
+            }
+        }
+        )*
+    };
+}
+
+impl_lit_value_int!(u8 => u8_unsuffixed, u16 => u16_unsuffixed, u32 => u32_unsuffixed, u64 => u64_unsuffixed, u128 => u128_unsuffixed, usize => usize_unsuffixed);
+impl_lit_value_int!(i8 => i8_unsuffixed, i16 => i16_unsuffixed, i32 => i32_unsuffixed, i64 => i64_unsuffixed, i128 => i128_unsuffixed, isize => isize_unsuffixed);
+
+macro_rules! impl_lit_value_float {
+    ($($ty:ty => $unsuffixed:ident),*) => {
+        $(
+        impl Parse for LitValue<$ty> {
+            fn parse(input: ParseStream) -> Result<Self> {
+                let (span, value): (Span, $ty) = if input.peek(LitInt) {
+                    let lit = input.parse::<LitInt>()?;
+                    (lit.span(), lit.base10_parse()?)
+                } else {
+                    let lit = parse_lit::<LitFloat>(input, "float literal")?;
+                    (lit.span(), lit.base10_parse()?)
+                };
+                // `base10_parse` returns infinity for a value that does not fit.
+                if !value.is_finite() {
+                    return Err(Error::new(span, "number out of range"));
+                }
+                Ok(Self { span, value })
+            }
+        }
+        /// Parsing never produces a non-finite value, but [`LitValue::new`] can.
+        /// Such a value has no literal, so it is emitted as `compile_error!`.
+        impl ToTokens for LitValue<$ty> {
+            fn to_tokens(&self, tokens: &mut TokenStream) {
+                if !self.value.is_finite() {
+                    let message = format!("`{}` cannot be a float literal", self.value);
+                    Error::new(self.span, message).to_compile_error().to_tokens(tokens);
+                    return;
+                }
+                literal_to_tokens(Literal::$unsuffixed(self.value), self.span, tokens);
+            }
+        }
         )*
     };
 }
 
-impl_parse_lit_value_int!(u8, u16, u32, u64, u128);
-impl_parse_lit_value_int!(i8, i16, i32, i64, i128);
+impl_lit_value_float!(f32 => f32_unsuffixed, f64 => f64_unsuffixed);
+
+impl Parse for LitValue<bool> {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lit = parse_lit::<LitBool>(input, "`true` or `false`")?;
+        Ok(Self {
+            span: lit.span,
+            value: lit.value,
+        })
+    }
+}
+impl ToTokens for LitValue<bool> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        LitBool::new(self.value, self.span).to_tokens(tokens);
+    }
+}
+
+impl Parse for LitValue<char> {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lit = parse_lit::<LitChar>(input, "character literal")?;
+        Ok(Self {
+            span: lit.span(),
+            value: lit.value(),
+        })
+    }
+}
+impl ToTokens for LitValue<char> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        literal_to_tokens(Literal::character(self.value), self.span, tokens);
+    }
+}
 
 impl Parse for LitValue<String> {
     fn parse(input: ParseStream) -> Result<Self> {
-        let lit = input.parse::<LitStr>()?;
-        Ok(LitValue {
+        let lit = parse_lit::<LitStr>(input, "string literal")?;
+        Ok(Self {
             span: lit.span(),
             value: lit.value(),
         })
     }
 }
+impl ToTokens for LitValue<String> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        literal_to_tokens(Literal::string(&self.value), self.span, tokens);
+    }
+}
 
-impl Parse for LitValue<f32> {
+impl Parse for LitValue<PathBuf> {
     fn parse(input: ParseStream) -> Result<Self> {
-        let lit = input.parse::<LitFloat>()?;
-        Ok(LitValue {
+        let lit = parse_lit::<LitStr>(input, "string literal")?;
+        Ok(Self {
             span: lit.span(),
-            value: lit.base10_parse()?,
+            value: lit.value().into(),
         })
     }
 }
-impl Parse for LitValue<f64> {
+impl ToTokens for LitValue<PathBuf> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let value = self.value.to_string_lossy();
+        literal_to_tokens(Literal::string(&value), self.span, tokens);
+    }
+}
+
+impl Parse for LitValue<Vec<u8>> {
     fn parse(input: ParseStream) -> Result<Self> {
-        let lit = input.parse::<LitFloat>()?;
-        Ok(LitValue {
+        let lit = parse_lit::<LitByteStr>(input, "byte string literal")?;
+        Ok(Self {
             span: lit.span(),
-            value: lit.base10_parse()?,
+            value: lit.value(),
         })
     }
 }
+impl ToTokens for LitValue<Vec<u8>> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        literal_to_tokens(Literal::byte_string(&self.value), self.span, tokens);
+    }
+}
+
+/// Value with the span of the literal it was parsed from.
+///
+/// Same as [`LitValue`].
+pub type Spanned<T> = LitValue<T>;
@@ -1,9 +1,15 @@
-use proc_macro2::{Ident, Spacing, Span, TokenStream};
+use crate::{
+    LitValue,
+    attr::{parse_with_end_span, path_span},
+};
+use proc_macro2::{Ident, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use std::ops::Range;
 use syn::{
-    MacroDelimiter, Result, Token, braced, bracketed,
+    Attribute, MacroDelimiter, Meta, Path, Result, Token, braced, bracketed,
     ext::IdentExt,
     parenthesized,
-    parse::{ParseBuffer, ParseStream, discouraged::Speculative},
+    parse::{Parse, ParseBuffer, ParseStream, discouraged::Speculative},
     token::{self},
 };
 
@@ -13,10 +19,174 @@ pub mod exports {
     pub use syn;
 }
 
+#[derive(Default)]
+pub struct Errors(Option<syn::Error>);
+
+impl Errors {
+    pub fn push(&mut self, e: syn::Error) {
+        if let Some(errors) = &mut self.0 {
+            errors.combine(e);
+        } else {
+            self.0 = Some(e);
+        }
+    }
+    pub fn combine(mut self, e: syn::Error) -> syn::Error {
+        self.push(e);
+        self.0.unwrap()
+    }
+    pub fn push_result<T>(&mut self, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.push(e);
+                None
+            }
+        }
+    }
+    pub fn finish(self) -> Result<()> {
+        if let Some(e) = self.0 { Err(e) } else { Ok(()) }
+    }
+
+    /// Parse one argument, and if it fails, record the error and skip to the next top-level separator.
+    pub fn parse_arg<T>(
+        &mut self,
+        input: ParseStream,
+        separator: &str,
+        f: impl FnOnce() -> Result<T>,
+    ) -> Option<T> {
+        let result = f().and_then(|value| {
+            if input.is_empty() || peek_arg_separator(input, separator) {
+                Ok(value)
+            } else {
+                Err(input.error(format!("expected `{separator}`")))
+            }
+        });
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.push(e);
+                while !input.is_empty() && !peek_arg_separator(input, separator) {
+                    let _ = input.parse::<TokenTree>();
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Implemented by `#[derive(StructMeta)]` for structs that can be used with `#[struct_meta(flatten)]`.
+pub trait Flatten: Sized {
+    type State;
+    fn names() -> Result<FlattenNames>;
+    fn new_state() -> Self::State;
+    fn parse_arg(
+        state: &mut Self::State,
+        input: ParseStream,
+        index: NameIndex,
+        span: Span,
+    ) -> Result<()>;
+    /// `span` is used for errors about missing arguments.
+    fn finish(state: Self::State, span: Span) -> Result<Self>;
+}
+
+pub struct FlattenNames {
+    pub flag: Vec<&'static str>,
+    pub name_value: Vec<&'static str>,
+    pub name_args: Vec<&'static str>,
+    /// The opening delimiter of each name in `name_args`, used in error messages.
+    pub name_args_opens: Vec<char>,
+    pub name_args_delimiters: String,
+    pub name_value_lookahead: bool,
+    pub help_params: Vec<HelpParam>,
+}
+impl FlattenNames {
+    /// Appends the names of the flattened field `field`, written as `name: Type`.
+    ///
+    /// Fails if a name is used by both, since one of the parameters could never be specified.
+    pub fn append(&mut self, field: &str, other: FlattenNames) -> Result<FlattenRanges> {
+        for name in other.all_names() {
+            if self.all_names().any(|n| n == name) {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    format!("duplicate parameter `{name}` in flattened field `{field}`"),
+                ));
+            }
+        }
+        self.name_value_lookahead |= other.name_value_lookahead;
+        self.help_params.extend(other.help_params);
+        self.name_args_opens.extend(other.name_args_opens);
+        for c in other.name_args_delimiters.chars() {
+            if !self.name_args_delimiters.contains(c) {
+                self.name_args_delimiters.push(c);
+            }
+        }
+        Ok(FlattenRanges {
+            flag: append_names(&mut self.flag, other.flag),
+            name_value: append_names(&mut self.name_value, other.name_value),
+            name_args: append_names(&mut self.name_args, other.name_args),
+        })
+    }
+    fn all_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.flag
+            .iter()
+            .chain(&self.name_value)
+            .chain(&self.name_args)
+            .copied()
+    }
+}
+fn append_names(names: &mut Vec<&'static str>, other: Vec<&'static str>) -> Range<usize> {
+    let start = names.len();
+    names.extend(other);
+    start..names.len()
+}
+
+/// A parameter listed by `#[struct_meta(help)]`.
+#[derive(Clone, Copy)]
+pub enum HelpParam {
+    Flag(&'static str),
+    NameValue(&'static str),
+    /// The name and the opening delimiter.
+    NameArgs(&'static str, char),
+}
+impl HelpParam {
+    fn name(&self) -> &'static str {
+        match self {
+            HelpParam::Flag(name) | HelpParam::NameValue(name) | HelpParam::NameArgs(name, _) => {
+                name
+            }
+        }
+    }
+    fn to_syntax(self, name_value_separator: &str) -> String {
+        match self {
+            HelpParam::Flag(name) => format!("`{name}`"),
+            HelpParam::NameValue(name) => format!("`{name} {name_value_separator} ...`"),
+            HelpParam::NameArgs(name, open) => name_args_syntax(name, open),
+        }
+    }
+}
+
+fn name_args_syntax(name: &str, open: char) -> String {
+    format!("`{name}{open}...{}`", close_of(open))
+}
+fn close_of(open: char) -> char {
+    match open {
+        '[' => ']',
+        '{' => '}',
+        _ => ')',
+    }
+}
+
+pub struct FlattenRanges {
+    pub flag: Range<usize>,
+    pub name_value: Range<usize>,
+    pub name_args: Range<usize>,
+}
+
 pub enum NameIndex {
     Flag(std::result::Result<usize, Ident>),
     NameValue(std::result::Result<usize, Ident>),
     NameArgs(std::result::Result<usize, Ident>),
+    Unknown(Ident),
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -26,33 +196,55 @@ pub fn try_parse_name(
     flag_rest: bool,
     name_value_names: &[&str],
     name_value_rest: bool,
+    name_value_lookahead: bool,
     name_args_names: &[&str],
-    name_args_rest: bool,
+    name_args_opens: &[char],
+    name_args_rest: Option<char>,
+    name_args_delimiters: &str,
     no_unnamed: bool,
+    allow_unknown: bool,
     name_filter: &dyn Fn(&str) -> bool,
+    name_value_separators: &[&str],
+    separator: &str,
+    help_params: &[HelpParam],
 ) -> Result<Option<(NameIndex, Span)>> {
     let may_flag = !flag_names.is_empty() || flag_rest;
-    let may_name_value = !name_value_names.is_empty() || name_value_rest;
+    let name_value_separator = name_value_separators.first().copied().unwrap_or("=");
+    let may_name_value = name_value_lookahead || name_value_rest;
+    let name_args_rest_open = name_args_rest;
+    let name_args_rest = name_args_rest.is_some();
     let may_name_args = !name_args_names.is_empty() || name_args_rest;
+    // If there are no unnamed parameters, `name[...]` and `name{...}` cannot be anything else.
+    let name_args_delimiters = if no_unnamed {
+        "([{"
+    } else {
+        name_args_delimiters
+    };
     let fork = input.fork();
     if let Ok(ident) = Ident::parse_any(&fork) {
         if name_filter(&ident.to_string()) {
             let span = ident.span();
             let mut kind = None;
-            if (no_unnamed || may_flag) && (fork.peek(Token![,]) || fork.is_empty()) {
+            if (no_unnamed || may_flag) && (fork.is_empty() || peek_arg_separator(&fork, separator))
+            {
                 if let Some(i) = name_index_of(flag_names, flag_rest, &ident) {
                     input.advance_to(&fork);
                     return Ok(Some((NameIndex::Flag(i), span)));
                 }
                 kind = Some(ArgKind::Flag);
-            } else if (no_unnamed || may_name_value) && peek_eq_op(&fork) {
+            } else if let (true, Some(len)) = (
+                no_unnamed || may_name_value || find(name_value_names, &ident).is_some(),
+                peek_separator(&fork, name_value_separators),
+            ) {
                 if let Some(i) = name_index_of(name_value_names, name_value_rest, &ident) {
-                    fork.parse::<Token![=]>()?;
+                    for _ in 0..len {
+                        fork.parse::<TokenTree>()?;
+                    }
                     input.advance_to(&fork);
                     return Ok(Some((NameIndex::NameValue(i), span)));
                 }
                 kind = Some(ArgKind::NameValue);
-            } else if (no_unnamed || may_name_args) && fork.peek(token::Paren) {
+            } else if (no_unnamed || may_name_args) && peek_delimiter(&fork, name_args_delimiters) {
                 if let Some(i) = name_index_of(name_args_names, name_args_rest, &ident) {
                     input.advance_to(&fork);
                     return Ok(Some((NameIndex::NameArgs(i), span)));
@@ -66,10 +258,12 @@ pub fn try_parse_name(
                     expected.push(format!("flag `{name}`"));
                 }
                 if let Some(name) = name_of(name_value_names, name_value_rest, &ident) {
-                    expected.push(format!("`{name} = ...`"));
+                    expected.push(format!("`{name} {name_value_separator} ...`"));
                 }
-                if let Some(name) = name_of(name_args_names, name_args_rest, &ident) {
-                    expected.push(format!("`{name}(...)`"));
+                if let Some(i) = find(name_args_names, &ident) {
+                    expected.push(name_args_syntax(name_args_names[i], name_args_opens[i]));
+                } else if let Some(open) = name_args_rest_open {
+                    expected.push(name_args_syntax(&ident.to_string(), open));
                 }
                 if !expected.is_empty() {
                     return Err(input.error(msg(
@@ -77,9 +271,14 @@ pub fn try_parse_name(
                         kind.map(|kind| Arg {
                             kind,
                             ident: &ident,
+                            name_value_separator,
+                            name_args_open: peek_open(&fork),
                         }),
                     )));
                 }
+                if allow_unknown {
+                    return Ok(Some((NameIndex::Unknown(ident), span)));
+                }
                 let help = if let Some(similar_name) =
                     find_similar_name(&[flag_names, name_value_names, name_args_names], &ident)
                 {
@@ -87,9 +286,20 @@ pub fn try_parse_name(
                 } else {
                     "".into()
                 };
-                return Err(input.error(format!(
+                let e = input.error(format!(
                     "cannot find parameter `{ident}` in this scope{help}"
-                )));
+                ));
+                if !help_params.is_empty() {
+                    let mut help_params = help_params.to_vec();
+                    help_params.sort_by_key(|p| p.name());
+                    let expected: Vec<_> = help_params
+                        .iter()
+                        .map(|p| p.to_syntax(name_value_separator))
+                        .collect();
+                    let help = format!("help: expected one of: {}", expected.join(", "));
+                    return Err(Errors(Some(e)).combine(input.error(help)));
+                }
+                return Err(e);
             }
         }
     }
@@ -103,12 +313,221 @@ pub fn try_parse_name(
     }
     Ok(None)
 }
-fn peek_eq_op(input: ParseStream) -> bool {
-    if let Some((p, _)) = input.cursor().punct() {
-        p.as_char() == '=' && p.spacing() == Spacing::Alone
+/// Parse tokens up to the next top-level comma.
+pub fn parse_unknown_arg(input: ParseStream, separator: &str) -> Result<TokenStream> {
+    let mut tokens = TokenStream::new();
+    while !input.is_empty() && !peek_arg_separator(input, separator) {
+        tokens.extend([input.parse::<TokenTree>()?]);
+    }
+    Ok(tokens)
+}
+/// Comma-separated argument list used by `#[struct_meta(to_tokens)]`.
+pub struct ArgTokens {
+    tokens: TokenStream,
+    name_value_separator: &'static str,
+    separator: &'static str,
+}
+impl Default for ArgTokens {
+    fn default() -> Self {
+        Self::new("=", ",")
+    }
+}
+
+impl ArgTokens {
+    pub fn new(name_value_separator: &'static str, separator: &'static str) -> Self {
+        Self {
+            tokens: TokenStream::new(),
+            name_value_separator,
+            separator,
+        }
+    }
+    fn push_arg(&mut self, arg: TokenStream) {
+        if !self.tokens.is_empty() {
+            arg_separator_to_tokens(self.separator, &mut self.tokens);
+        }
+        self.tokens.extend(arg);
+    }
+    pub fn push_tokens(&mut self, tokens: TokenStream) {
+        if !tokens.is_empty() {
+            self.push_arg(tokens);
+        }
+    }
+    pub fn push_unnamed(&mut self, value: &impl ToTokens) {
+        self.push_arg(value.to_token_stream());
+    }
+    pub fn push_unnamed_list<T: ToTokens>(&mut self, values: &[T], separator: &str) {
+        let mut tokens = TokenStream::new();
+        list_to_tokens(values, separator, &mut tokens);
+        self.push_tokens(tokens);
+    }
+    pub fn push_flag(&mut self, name: &str, span: Span) {
+        self.push_arg(Ident::new(name, span).into_token_stream());
+    }
+    pub fn push_name_value(&mut self, name: &str, span: Span, value: &impl ToTokens) {
+        let mut tokens = Ident::new(name, span).into_token_stream();
+        match self.name_value_separator {
+            ":" => <Token![:]>::default().to_tokens(&mut tokens),
+            "=>" => <Token![=>]>::default().to_tokens(&mut tokens),
+            _ => <Token![=]>::default().to_tokens(&mut tokens),
+        }
+        value.to_tokens(&mut tokens);
+        self.push_arg(tokens);
+    }
+    pub fn push_name_args(
+        &mut self,
+        name: &str,
+        span: Span,
+        delimiter: &MacroDelimiter,
+        args: &impl ToTokens,
+    ) {
+        let mut tokens = Ident::new(name, span).into_token_stream();
+        surround_macro_delimiter(delimiter, &mut tokens, |tokens| args.to_tokens(tokens));
+        self.push_arg(tokens);
+    }
+    pub fn push_name_arg_list<T: ToTokens>(
+        &mut self,
+        name: &str,
+        span: Span,
+        delimiter: &MacroDelimiter,
+        separator: &str,
+        args: &[T],
+    ) {
+        let mut tokens = Ident::new(name, span).into_token_stream();
+        surround_macro_delimiter(delimiter, &mut tokens, |tokens| {
+            list_to_tokens(args, separator, tokens)
+        });
+        self.push_arg(tokens);
+    }
+    pub fn finish(self, tokens: &mut TokenStream) {
+        tokens.extend(self.tokens);
+    }
+}
+
+fn list_to_tokens<T: ToTokens>(values: &[T], separator: &str, tokens: &mut TokenStream) {
+    for (i, value) in values.iter().enumerate() {
+        if i != 0 {
+            arg_separator_to_tokens(separator, tokens);
+        }
+        value.to_tokens(tokens);
+    }
+}
+fn arg_separator_to_tokens(separator: &str, tokens: &mut TokenStream) {
+    match separator {
+        ";" => <Token![;]>::default().to_tokens(tokens),
+        "|" => <Token![|]>::default().to_tokens(tokens),
+        "+" => <Token![+]>::default().to_tokens(tokens),
+        _ => <Token![,]>::default().to_tokens(tokens),
+    }
+}
+
+/// Returns true if `input` starts with `separator` (`,`, `;`, `|` or `+`).
+pub fn peek_arg_separator(input: ParseStream, separator: &str) -> bool {
+    let Some((p, cursor)) = input.cursor().punct() else {
+        return false;
+    };
+    if p.as_char().to_string() != separator {
+        return false;
+    }
+    match p.as_char() {
+        '|' | '+' if p.spacing() == Spacing::Joint => {
+            // `||`, `|=`, `++` and `+=` are not separators.
+            !cursor
+                .punct()
+                .is_some_and(|(p1, _)| p1.as_char() == p.as_char() || p1.as_char() == '=')
+        }
+        _ => true,
+    }
+}
+pub fn parse_arg_separator(input: ParseStream, separator: &str) -> Result<()> {
+    if peek_arg_separator(input, separator) {
+        input.parse::<TokenTree>()?;
+        Ok(())
     } else {
-        false
+        Err(input.error(format!("expected `{separator}`")))
+    }
+}
+/// Parse zero or more values separated by `separator`, with an optional trailing separator.
+pub fn parse_separated<T: Parse>(input: ParseStream, separator: &str) -> Result<Vec<T>> {
+    let mut values = Vec::new();
+    while !input.is_empty() {
+        values.push(input.parse()?);
+        if input.is_empty() {
+            break;
+        }
+        parse_arg_separator(input, separator)?;
     }
+    Ok(values)
+}
+
+/// Parse the value of `name = true` or `name = false` for a flag.
+pub fn parse_flag_value(input: ParseStream, span: Span) -> Result<Option<Span>> {
+    let value = input.parse::<LitValue<bool>>()?;
+    Ok(if *value { Some(span) } else { None })
+}
+
+pub fn lit_value<T: Clone>(value: &T) -> LitValue<T> {
+    LitValue::new(value.clone(), Span::call_site())
+}
+
+pub fn parse_variant_name(input: ParseStream, names: &[&str], separator: &str) -> Result<usize> {
+    let fork = input.fork();
+    let mut help = String::new();
+    if let Ok(ident) = Ident::parse_any(&fork) {
+        if let Some(index) = find(names, &ident) {
+            input.advance_to(&fork);
+            if !input.is_empty() {
+                parse_arg_separator(input, separator)?;
+            }
+            return Ok(index);
+        }
+        if let Some(similar_name) = find_similar_name(&[names], &ident) {
+            help = format!(" (help: a variant with a similar name exists: `{similar_name}`)");
+        }
+    }
+    let expected: Vec<_> = names.iter().map(|name| format!("`{name}`")).collect();
+    Err(input.error(format!("{}{help}", msg(&expected, None))))
+}
+fn peek_open(input: ParseStream) -> char {
+    if input.peek(token::Bracket) {
+        '['
+    } else if input.peek(token::Brace) {
+        '{'
+    } else {
+        '('
+    }
+}
+fn peek_delimiter(input: ParseStream, delimiters: &str) -> bool {
+    (delimiters.contains('(') && input.peek(token::Paren))
+        || (delimiters.contains('[') && input.peek(token::Bracket))
+        || (delimiters.contains('{') && input.peek(token::Brace))
+}
+/// Returns the number of punctuations of the name-value separator at the beginning of `input`.
+fn peek_separator(input: ParseStream, separators: &[&str]) -> Option<usize> {
+    let (p0, cursor) = input.cursor().punct()?;
+    for &separator in separators {
+        let len = match separator {
+            "=" | ":" => {
+                if p0.spacing() == Spacing::Alone && p0.as_char().to_string() == separator {
+                    1
+                } else {
+                    continue;
+                }
+            }
+            "=>" => {
+                if p0.spacing() == Spacing::Joint
+                    && p0.as_char() == '='
+                    && cursor.punct().is_some_and(|(p1, _)| p1.as_char() == '>')
+                {
+                    2
+                } else {
+                    continue;
+                }
+            }
+            _ => continue,
+        };
+        return Some(len);
+    }
+    None
 }
 fn name_index_of(
     names: &[&str],
@@ -154,8 +573,11 @@ fn msg(expected: &[String], found: Option<Arg>) -> String {
         m.push_str(", found ");
         m.push_str(&match arg.kind {
             ArgKind::Flag => format!("`{}`", arg.ident),
-            ArgKind::NameValue => format!("`{} = ...`", arg.ident),
-            ArgKind::NameArgs => format!("`{}`(...)", arg.ident),
+            ArgKind::NameValue => format!("`{} {} ...`", arg.ident, arg.name_value_separator),
+            ArgKind::NameArgs => {
+                let open = arg.name_args_open;
+                format!("`{}`{open}...{}", arg.ident, close_of(open))
+            }
         });
     }
     m
@@ -218,10 +640,144 @@ fn distance(s0: &[char], s1: &[char]) -> Option<usize> {
     None
 }
 
+pub fn check_conflicts(
+    name: &str,
+    span: Option<Span>,
+    other: &str,
+    other_span: Option<Span>,
+) -> Result<()> {
+    if let (Some(span), Some(_)) = (span, other_span) {
+        return Err(syn::Error::new(
+            span,
+            format!("`{name}` cannot be used with `{other}`"),
+        ));
+    }
+    Ok(())
+}
+pub fn check_requires(
+    name: &str,
+    span: Option<Span>,
+    other: &str,
+    other_span: Option<Span>,
+) -> Result<()> {
+    if let (Some(span), None) = (span, other_span) {
+        return Err(syn::Error::new(
+            span,
+            format!("`{name}` requires `{other}`"),
+        ));
+    }
+    Ok(())
+}
+/// `span` is used for the error about a missing argument.
+pub fn check_group(
+    group: &str,
+    members: &[(&str, Option<Span>)],
+    required: bool,
+    span: Span,
+) -> Result<()> {
+    let mut used = None;
+    for (name, span) in members {
+        if let Some(span) = span {
+            if let Some(used) = used {
+                return Err(syn::Error::new(
+                    *span,
+                    format!("`{name}` cannot be used with `{used}` (group `{group}`)"),
+                ));
+            }
+            used = Some(name);
+        }
+    }
+    if required && used.is_none() {
+        let names: Vec<_> = members
+            .iter()
+            .map(|(name, _)| format!("`{name}`"))
+            .collect();
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "missing one of arguments {} (group `{group}`)",
+                names.join(", ")
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Parse the arguments of all attributes with one of `paths` as one argument list.
+pub fn parse_attrs<T: Parse>(
+    attrs: &[Attribute],
+    paths: &[&str],
+    separator: &str,
+) -> Result<Option<T>> {
+    let mut tokens = TokenStream::new();
+    let mut span = None;
+    for attr in attrs {
+        if !paths.iter().any(|path| is_path(attr.path(), path)) {
+            continue;
+        }
+        span.get_or_insert_with(|| path_span(attr.path()));
+        let args = match &attr.meta {
+            Meta::Path(_) => continue,
+            meta => meta.require_list()?.tokens.clone(),
+        };
+        if args.is_empty() {
+            continue;
+        }
+        if !tokens.is_empty() && !ends_with_arg_separator(&tokens, separator) {
+            arg_separator_to_tokens(separator, &mut tokens);
+        }
+        tokens.extend(args);
+    }
+    if let Some(span) = span {
+        parse_with_end_span(tokens, span).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+pub fn parse_attrs_required<T: Parse>(
+    attrs: &[Attribute],
+    paths: &[&str],
+    separator: &str,
+) -> Result<T> {
+    if let Some(value) = parse_attrs(attrs, paths, separator)? {
+        Ok(value)
+    } else {
+        Err(syn::Error::new(
+            Span::call_site(),
+            format!("missing attribute `#[{}(...)]`", paths[0]),
+        ))
+    }
+}
+fn is_path(path: &Path, s: &str) -> bool {
+    let mut names = s.split("::");
+    path.segments
+        .iter()
+        .all(|seg| seg.arguments.is_none() && names.next().is_some_and(|name| seg.ident == name))
+        && names.next().is_none()
+}
+fn ends_with_arg_separator(tokens: &TokenStream, separator: &str) -> bool {
+    if let Some(TokenTree::Punct(p)) = tokens.clone().into_iter().last() {
+        p.as_char().to_string() == separator
+    } else {
+        false
+    }
+}
+
 pub fn is_snake_case(s: &str) -> bool {
     s.chars()
         .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
 }
+pub fn is_lowercase(s: &str) -> bool {
+    s.chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+}
+pub fn is_upper_camel_case(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_uppercase()) && s.chars().all(|c| c.is_ascii_alphanumeric())
+}
+pub fn is_screaming_snake_case(s: &str) -> bool {
+    s.chars()
+        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
 
 pub fn surround_macro_delimiter<F>(this: &MacroDelimiter, tokens: &mut TokenStream, f: F)
 where
@@ -234,6 +790,26 @@ where
     }
 }
 
+/// Parse the arguments of `name(...)`, `name[...]` or `name{...}` with the delimiters in `delimiters`.
+pub fn parse_name_args_delimiter<'a>(
+    input: &ParseBuffer<'a>,
+    delimiters: &str,
+) -> Result<(MacroDelimiter, ParseBuffer<'a>)> {
+    if peek_delimiter(input, delimiters) {
+        return parse_macro_delimiter(input);
+    }
+    let expected: Vec<_> = delimiters.chars().map(|c| format!("`{c}`")).collect();
+    Err(input.error(format!("expected {}", expected.join(", "))))
+}
+pub fn macro_delimiter(delimiter: char) -> MacroDelimiter {
+    let span = Span::call_site();
+    match delimiter {
+        '[' => MacroDelimiter::Bracket(token::Bracket(span)),
+        '{' => MacroDelimiter::Brace(token::Brace(span)),
+        _ => MacroDelimiter::Paren(token::Paren(span)),
+    }
+}
+
 pub fn parse_macro_delimiter<'a>(
     input: &ParseBuffer<'a>,
 ) -> Result<(MacroDelimiter, ParseBuffer<'a>)> {
@@ -287,4 +863,6 @@ enum ArgKind {
 struct Arg<'a> {
     kind: ArgKind,
     ident: &'a Ident,
+    name_value_separator: &'a str,
+    name_args_open: char,
 }
@@ -7,12 +7,18 @@ See [`#[derive(StructMeta)]`](macro@StructMeta) documentation for details.
 #[doc(hidden)]
 pub mod helpers;
 
-// mod easy_syntax;
-// pub use easy_syntax::*;
+mod easy_syntax;
+pub use easy_syntax::*;
 
 mod arg_types;
 pub use arg_types::*;
 
+mod schema;
+pub use schema::*;
+
+mod attr;
+pub use attr::*;
+
 // #[include_doc("../../doc/to_tokens.md", start)]
 /// Derive [`quote::ToTokens`] for syntax tree node.
 ///
@@ -446,15 +452,32 @@ pub use structmeta_derive::Parse;
 ///   - [NameArgs or Flag style](#nameargs-or-flag-style)
 ///   - [NameArgList style](#namearglist-style)
 ///   - [NameArgList or Flag style](#namearglist-or-flag-style)
+///   - [Delimiters](#delimiters)
+///   - [Separators](#separators)
 ///   - [Optional named parameter](#optional-named-parameter)
+///   - [Default value](#default-value)
+///   - [Repeated named parameter](#repeated-named-parameter)
+///   - [Alias](#alias)
+///   - [Renaming parameters](#renaming-parameters)
+///   - [Name-value separator](#name-value-separator)
 ///   - [Rest named parameter](#rest-named-parameter)
+///   - [Flatten](#flatten)
 /// - [Unnamed parameter](#unnamed-parameter)
 ///   - [Required unnamed parameter](#required-unnamed-parameter)
 ///   - [Optional unnamed parameter](#optional-unnamed-parameter)
 ///   - [Variadic unnamed parameter](#variadic-unnamed-parameter)
+///   - [Unnamed or named parameter](#unnamed-or-named-parameter)
+/// - [Primitive types](#primitive-types)
 /// - [Parameter order](#parameter-order)
+/// - [Parameter constraints](#parameter-constraints)
+/// - [Enum](#enum)
+/// - [Collecting errors](#collecting-errors)
+/// - [Unknown arguments](#unknown-arguments)
+/// - [Converting back to tokens](#converting-back-to-tokens)
+/// - [Inspecting parameters](#inspecting-parameters)
 /// - [Helper attribute `#[struct_meta(...)]`](#helper-attribute-struct_meta)
 /// - [Uses with `#[proc_macro_derive]`](#uses-with-proc_macro_derive)
+///   - [Finding attributes](#finding-attributes)
 /// - [Uses with `#[proc_macro_attribute]`](#uses-with-proc_macro_attribute)
 /// - [Parsing ambiguous arguments](#parsing-ambiguous-arguments)
 ///   - [`#[struct_meta(name_filter = "...")]`](#struct_metaname_filter--)
@@ -490,7 +513,8 @@ pub use structmeta_derive::Parse;
 /// - field with `#[struct_meta(name = "...")]` in tuple struct.
 /// - However, fields that meet the following conditions are excluded
 ///   - field with `#[struct_meta(unnamed)]`
-///   - field with type `HashMap<String, _>`
+///   - field with `#[struct_meta(flatten)]`
+///   - field of [rest parameter](#rest-named-parameter)
 ///
 /// "Named parameter" is a parameter that specifies with a name, such as `#[attr(flag, x = 10, y(1, 2, 3))]`.
 ///
@@ -505,7 +529,7 @@ pub use structmeta_derive::Parse;
 ///
 /// | field type | field type (with span)       | style                                             | example                         |
 /// | ---------- | ---------------------------- | ------------------------------------------------- | ------------------------------- |
-/// | `bool`     | [`Flag`]                     | [Flag](#flag-style)                               | `name`                          |
+/// | `bool`     | [`Flag`]                     | [Flag](#flag-style)                               | `name` or `name = true`         |
 /// | `T`        | [`NameValue<T>`]             | [NameValue](#namevalue-style)                     | `name = value`                  |
 /// |            | [`NameValue<Option<T>>`]     | [NameValue or Flag](#namevalue-or-flag-style)     | `name = value` or `name`        |
 /// |            | [`NameArgs<T>`]              | [NameArgs](#nameargs-or-flag-style)               | `name(args)`                    |
@@ -513,14 +537,21 @@ pub use structmeta_derive::Parse;
 /// | `Vec<T>`   | [`NameArgs<Vec<T>>`]         | [NameArgList](#namearglist-style)                 | `name(arg, arg, ...)`           |
 /// |            | [`NameArgs<Option<Vec<T>>>`] | [NameArgList or Flag](#namearglist-or-flag-style) | `name(arg, arg, ...)` or `name` |
 ///
-/// Note: the type `T` in the table above needs to implement [`syn::parse::Parse`].
+/// Note: the type `T` in the table above needs to implement [`syn::parse::Parse`] or be a [primitive type](#primitive-types).
 ///
-/// With the above type as P (`bool` and `Flag` are excluded), you can also use the following types.
+/// With the above type as P (`bool` and `Flag` are excluded, except for `Option<bool>`), you can also use the following types.
 ///
-/// | field type           | effect                                          |
-/// | -------------------- | ----------------------------------------------- |
-/// | `Option<P>`          | [optional parameter](#optional-named-parameter) |
-/// | `HashMap<String, P>` | [rest parameter](#rest-named-parameter)         |
+/// | field type                               | effect                                          |
+/// | ---------------------------------------- | ----------------------------------------------- |
+/// | `Option<P>`                              | [optional parameter](#optional-named-parameter) |
+/// | `HashMap<String, P>`                     | [rest parameter](#rest-named-parameter)         |
+/// | `HashMap<Ident, P>`                      | [rest parameter](#rest-named-parameter)         |
+/// | `BTreeMap<String, P>`                    | [rest parameter](#rest-named-parameter)         |
+/// | `Vec<(Ident, P)>`                        | [rest parameter](#rest-named-parameter)         |
+/// | [`RestArgs<P>`]                          | [rest parameter](#rest-named-parameter)         |
+/// | `Vec<NameValue<T>>`                      | [repeated parameter](#repeated-named-parameter) |
+/// | `Vec<NameArgs<T>>`                       | [repeated parameter](#repeated-named-parameter) |
+/// | `Vec<P>` with `#[struct_meta(repeated)]` | [repeated parameter](#repeated-named-parameter) |
 ///
 /// ## Flag style
 ///
@@ -567,6 +598,73 @@ pub use structmeta_derive::Parse;
 /// # syn::Result::Ok(())
 /// ```
 ///
+/// The value can also be specified explicitly with `name = true` or `name = false`.
+/// This is useful when generating an attribute from a `macro_rules!` with `$enabled:literal`.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     a: bool,
+///     b: bool,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(a = true, b = false)]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.a, true);
+/// assert_eq!(args.b, false);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// A field with the type `Option<bool>` distinguishes whether the argument is specified.
+/// It is `None` if omitted, `Some(true)` for `name` or `name = true`, and `Some(false)` for `name = false`.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     a: Option<bool>,
+///     b: Option<bool>,
+///     c: Option<bool>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(a, b = false)]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.a, Some(true));
+/// assert_eq!(args.b, Some(false));
+/// assert_eq!(args.c, None);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// If you specify `#[struct_meta(negatable)]`, the flag can also be turned off with `no_name`.
+/// This is useful for flags whose default is `true`.
+/// Specifying both `name` and `no_name` is an error.
+/// `#[struct_meta(negatable)]` on a struct applies to all flags of the struct.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     #[struct_meta(negatable, default = true)]
+///     debug: bool,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(no_debug)]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.debug, false);
+///
+/// let attr: Attribute = parse_quote!(#[attr()]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.debug, true);
+/// # syn::Result::Ok(())
+/// ```
+///
 /// ## NameValue style
 ///
 /// A field with type `T` or `NameValue<T>` will be `name = value` style parameter.
@@ -709,6 +807,79 @@ pub use structmeta_derive::Parse;
 /// # syn::Result::Ok(())
 /// ```
 ///
+/// ## Delimiters
+///
+/// By default, NameArgs and NameArgList style arguments must be surrounded by `(...)`.
+///
+/// If you specify `#[struct_meta(delimiters = "...")]`, you can also use `[...]` or `{...}`.
+/// The value is a combination of `(`, `[` and `{`, and can also be specified for each field.
+/// The delimiter actually used is returned by [`NameArgs::delimiter`].
+///
+/// ```rust
+/// use structmeta::{NameArgs, StructMeta};
+/// use syn::{parse_quote, Attribute, LitStr, MacroDelimiter};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     #[struct_meta(delimiters = "([{")]
+///     fields: NameArgs<Vec<LitStr>>,
+///     #[struct_meta(delimiters = "[")]
+///     tags: Vec<LitStr>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(fields{"a", "b"}, tags["x"])]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.fields.args.len(), 2);
+/// assert!(matches!(args.fields.delimiter(), MacroDelimiter::Brace(_)));
+/// assert_eq!(args.tags.len(), 1);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// ## Separators
+///
+/// By default, the elements of NameArgList style arguments and [variadic unnamed parameters](#variadic-unnamed-parameter) are separated by `,`.
+///
+/// If you specify `#[struct_meta(separator = "...")]` for the field, you can use `;`, `|` or `+` instead.
+///
+/// ```rust
+/// use structmeta::{NameArgs, StructMeta};
+/// use syn::{parse_quote, Attribute, Ident, WherePredicate};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     #[struct_meta(separator = "|")]
+///     variants: NameArgs<Vec<Ident>>,
+///     #[struct_meta(separator = ";")]
+///     bound: Vec<WherePredicate>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(variants(A | B | C), bound(T: Clone + Send; U: Debug))]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.variants.args.len(), 3);
+/// assert_eq!(args.bound.len(), 2);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// If you specify `#[struct_meta(separator = "...")]` for the struct, it is used to separate the arguments themselves.
+/// This is useful for function-like macros such as `my_macro! { a = 1; b = 2; }`.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::LitInt;
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(separator = ";")]
+/// struct Args {
+///     a: LitInt,
+///     b: LitInt,
+/// }
+///
+/// let args: Args = syn::parse_str("a = 1; b = 2;")?;
+/// assert_eq!(args.a.base10_parse::<u32>()?, 1);
+/// assert_eq!(args.b.base10_parse::<u32>()?, 2);
+/// # syn::Result::Ok(())
+/// ```
+///
 /// ## Optional named parameter
 ///
 /// If you use `Option` for the field type, it becomes an optional parameter.
@@ -735,9 +906,172 @@ pub use structmeta_derive::Parse;
 /// # syn::Result::Ok(())
 /// ```
 ///
+/// ## Default value
+///
+/// If you specify `#[struct_meta(default)]` for a field, the value of [`Default::default()`] is used when the argument is omitted.
+///
+/// If you specify `#[struct_meta(default = expr)]`, the value of `expr` is used instead.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitInt, LitStr};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     #[struct_meta(default)]
+///     a: Vec<LitStr>,
+///     #[struct_meta(default = default_b())]
+///     b: LitInt,
+/// }
+/// fn default_b() -> LitInt {
+///     parse_quote!(10)
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr()]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.a.len(), 0);
+/// assert_eq!(args.b.base10_parse::<u32>()?, 10);
+///
+/// let attr: Attribute = parse_quote!(#[attr(a("x"), b = 20)]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.a.len(), 1);
+/// assert_eq!(args.b.base10_parse::<u32>()?, 20);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// `#[struct_meta(default)]` can also be used for [unnamed parameter](#optional-unnamed-parameter).
+///
+/// ## Repeated named parameter
+///
+/// If `Vec<NameValue<T>>` or `Vec<NameArgs<T>>` is used for the field type, the parameter can be specified multiple times, and the arguments are stored in the order they appear.
+///
+/// To use other types, specify `#[struct_meta(repeated)]` for the field of type `Vec<P>`.
+/// Each element of the `Vec` is parsed in the same way as a field of type `P`.
+///
+/// If the parameter is omitted, the field will be an empty `Vec`.
+///
+/// ```rust
+/// use structmeta::{NameArgs, NameValue, StructMeta};
+/// use syn::{parse_quote, Attribute, Ident, LitStr};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     derive: Vec<NameValue<Ident>>,
+///     field: Vec<NameArgs<Ident>>,
+///     #[struct_meta(repeated)]
+///     bound: Vec<LitStr>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(derive = Debug, field(a), derive = Clone, bound = "T: Copy")]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.derive.len(), 2);
+/// assert_eq!(args.derive[0].value, "Debug");
+/// assert_eq!(args.derive[1].value, "Clone");
+/// assert_eq!(args.field.len(), 1);
+/// assert_eq!(args.bound.len(), 1);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// ## Alias
+///
+/// `#[struct_meta(alias = "...")]` adds another name for the parameter. It can be specified multiple times.
+///
+/// Specifying the same parameter with multiple names is an error.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitStr, Result};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     #[struct_meta(alias = "rename_to")]
+///     rename: Option<LitStr>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(rename = "a")]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.rename.unwrap().value(), "a");
+///
+/// let attr: Attribute = parse_quote!(#[attr(rename_to = "b")]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.rename.unwrap().value(), "b");
+///
+/// let attr: Attribute = parse_quote!(#[attr(rename = "a", rename_to = "b")]);
+/// let args: Result<Args> = attr.parse_args();
+/// assert!(args.is_err());
+/// # syn::Result::Ok(())
+/// ```
+///
+/// ## Renaming parameters
+///
+/// By default, the field name is used as the parameter name.
+///
+/// By attaching `#[struct_meta(rename_all = "...")]` to struct definition, field names are converted as follows.
+///
+/// | value                    | field name | parameter name |
+/// | ------------------------ | ---------- | -------------- |
+/// | `"lowercase"`            | `max_len`  | `maxlen`       |
+/// | `"camelCase"`            | `max_len`  | `maxLen`       |
+/// | `"PascalCase"`           | `max_len`  | `MaxLen`       |
+/// | `"SCREAMING_SNAKE_CASE"` | `max_len`  | `MAX_LEN`      |
+///
+/// Parameter names specified by `#[struct_meta(name = "...")]` and `#[struct_meta(alias = "...")]` are not converted.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitInt};
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(rename_all = "camelCase")]
+/// struct Args {
+///     max_len: Option<LitInt>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(maxLen = 10)]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.max_len.unwrap().base10_parse::<u32>()?, 10);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// ## Name-value separator
+///
+/// By default, NameValue style arguments are separated by `=`.
+///
+/// If you specify `#[struct_meta(name_value_separator = "...")]`, you can use `:` or `=>` instead.
+/// Multiple separators can be allowed, such as `#[struct_meta(name_value_separator = ":" | "=")]`.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitInt};
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(name_value_separator = ":")]
+/// struct Args {
+///     width: LitInt,
+///     height: LitInt,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(width: 10, height: 20)]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.width.base10_parse::<u32>()?, 10);
+/// assert_eq!(args.height.base10_parse::<u32>()?, 20);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// When `:` is allowed, `name: value` is always treated as a named argument, not as the beginning of an unnamed argument.
+/// Paths such as `a::b` are not affected.
+///
 /// ## Rest named parameter
 ///
-/// If `HashMap<String, _>` is used for the field type, the field will contain named arguments that are not associated with the field.
+/// If one of the following types is used for the field type, the field will contain named arguments that are not associated with the field.
+///
+/// | field type            | order               | span of name |
+/// | --------------------- | ------------------- | ------------ |
+/// | `HashMap<String, P>`  | unspecified         |              |
+/// | `HashMap<Ident, P>`   | unspecified         | ✔            |
+/// | `BTreeMap<String, P>` | sorted by name      |              |
+/// | `Vec<(Ident, P)>`     | order of appearance | ✔            |
+/// | [`RestArgs<P>`]       | order of appearance | ✔            |
 ///
 /// ```rust
 /// use std::collections::HashMap;
@@ -759,6 +1093,78 @@ pub use structmeta_derive::Parse;
 /// # syn::Result::Ok(())
 /// ```
 ///
+/// [`RestArgs<P>`] keeps the order of appearance and the spans of the names, and can be looked up by name.
+///
+/// ```rust
+/// use structmeta::{RestArgs, StructMeta};
+/// use syn::{parse_quote, Attribute, LitInt};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     a: Option<LitInt>,
+///     rest: RestArgs<LitInt>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(c = 30, a = 10, b = 20)]);
+/// let args: Args = attr.parse_args()?;
+/// let names: Vec<_> = args.rest.names().map(|name| name.to_string()).collect();
+/// assert_eq!(names, vec!["c", "b"]);
+/// assert_eq!(args.rest.get("b").unwrap().base10_parse::<u32>()?, 20);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// To collect only unknown [Flag style](#flag-style) arguments, use `HashSet<String>`, `BTreeSet<String>`, or `Vec<Ident>` with `#[struct_meta(rest)]`.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, Ident, LitInt};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     a: Option<LitInt>,
+///     #[struct_meta(rest)]
+///     flags: Vec<Ident>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(y, a = 10, x)]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.flags, vec!["y", "x"]);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// ## Flatten
+///
+/// `#[struct_meta(flatten)]` merges the named parameters of another type into the parameters of the struct.
+///
+/// The type of the field must be a struct with `#[derive(StructMeta)]` that has only named parameters.
+/// The merged parameters must have distinct names. Otherwise, parsing fails with an error.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitStr};
+///
+/// #[derive(StructMeta)]
+/// struct Common {
+///     rename: Option<LitStr>,
+///     skip: bool,
+/// }
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     #[struct_meta(unnamed)]
+///     path: LitStr,
+///     #[struct_meta(flatten)]
+///     common: Common,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr("a", skip, rename = "b")]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.path.value(), "a");
+/// assert_eq!(args.common.skip, true);
+/// assert_eq!(args.common.rename.unwrap().value(), "b");
+/// # syn::Result::Ok(())
+/// ```
+///
 /// # Unnamed parameter
 ///
 /// The following field will be "Unnamed parameter".
@@ -767,7 +1173,8 @@ pub use structmeta_derive::Parse;
 /// - field with `#[struct_meta(unnamed)]` in record struct.
 /// - However, fields that meet the following conditions are excluded
 ///   - field with `#[struct_meta(name = "...")]`
-///   - field with type `HashMap<String, _>`
+///   - field with `#[struct_meta(flatten)]`
+///   - field of [rest parameter](#rest-named-parameter)
 ///
 /// "Unnamed parameter" is a value-only parameter, such as `#[attr("abc", 10, 20)]`.
 ///
@@ -777,7 +1184,7 @@ pub use structmeta_derive::Parse;
 /// | `Option<T>` | [optional parameter](#optional-unnamed-parameter) |
 /// | `Vec<T>`    | [variadic parameter](#variadic-unnamed-parameter) |
 ///
-/// The type `T` in the table above needs to implement [`syn::parse::Parse`].
+/// The type `T` in the table above needs to implement [`syn::parse::Parse`] or be a [primitive type](#primitive-types).
 ///
 /// ## Required unnamed parameter
 ///
@@ -821,6 +1228,21 @@ pub use structmeta_derive::Parse;
 /// # syn::Result::Ok(())
 /// ```
 ///
+/// Fields with `#[struct_meta(default)]` or `#[struct_meta(default = expr)]` will also be optional parameters.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitInt, LitStr};
+///
+/// #[derive(StructMeta)]
+/// struct Args(LitStr, #[struct_meta(default = parse_quote!(0))] LitInt);
+///
+/// let attr: Attribute = parse_quote!(#[attr("a")]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.1.base10_parse::<u32>()?, 0);
+/// # syn::Result::Ok(())
+/// ```
+///
 /// ## Variadic unnamed parameter
 ///
 /// If you use `Vec` as the field type, multiple arguments can be stored in a single field.
@@ -849,6 +1271,74 @@ pub use structmeta_derive::Parse;
 /// # syn::Result::Ok(())
 /// ```
 ///
+/// ## Unnamed or named parameter
+///
+/// If you specify both `#[struct_meta(unnamed)]` and `#[struct_meta(name = "...")]`, the parameter can be specified either as an unnamed argument or as a `name = value` argument.
+/// Specifying it in both forms is an error.
+///
+/// In the order of unnamed parameters, such a parameter is treated as an optional unnamed parameter.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitStr};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     #[struct_meta(unnamed, name = "path")]
+///     path: LitStr,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr("/x")]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.path.value(), "/x");
+///
+/// let attr: Attribute = parse_quote!(#[attr(path = "/x")]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.path.value(), "/x");
+///
+/// let attr: Attribute = parse_quote!(#[attr("/x", path = "/y")]);
+/// assert!(attr.parse_args::<Args>().is_err());
+/// # syn::Result::Ok(())
+/// ```
+///
+/// # Primitive types
+///
+/// The following types are parsed from literals as a value of `T`, a value in `NameValue<T>` or an argument in `NameArgs<T>`.
+///
+/// | type                    | literal                          |
+/// | ----------------------- | -------------------------------- |
+/// | `u8` .. `u128`, `usize` | integer literal                  |
+/// | `i8` .. `i128`, `isize` | integer literal                  |
+/// | `f32`, `f64`            | float literal or integer literal |
+/// | `bool`                  | `true` or `false`                |
+/// | `char`                  | character literal                |
+/// | `String`, `PathBuf`     | string literal                   |
+///
+/// A literal of a different kind or a value out of range is reported as an error at the span of the value.
+/// To keep the span, use [`NameValue<T>`] or [`Spanned<T>`].
+///
+/// A field of type `bool` is still a [Flag style](#flag-style) named parameter.
+///
+/// ```rust
+/// use structmeta::{Spanned, StructMeta};
+/// use syn::{parse_quote, Attribute};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     #[struct_meta(unnamed)]
+///     name: String,
+///     size: u32,
+///     scale: Option<Spanned<f64>>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr("abc", size = 4, scale = 1.5)]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.name, "abc");
+/// assert_eq!(args.size, 4);
+/// assert_eq!(*args.scale.unwrap(), 1.5);
+/// # syn::Result::Ok(())
+/// ```
+///
 /// # Parameter order
 ///
 /// The parameters must be in the following order.
@@ -859,14 +1349,307 @@ pub use structmeta_derive::Parse;
 ///   - Variadic
 /// - Named
 ///
+/// If you specify `#[struct_meta(unnamed_anywhere)]`, unnamed arguments can also be written after named arguments.
+/// Unnamed arguments are still assigned to the unnamed parameters in the order they appear.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitInt, LitStr};
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(unnamed_anywhere)]
+/// struct Args {
+///     #[struct_meta(unnamed)]
+///     x: LitStr,
+///     #[struct_meta(unnamed)]
+///     y: Option<LitStr>,
+///     a: Option<LitInt>,
+///     b: bool,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(a = 1, "x", b, "y")]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.x.value(), "x");
+/// assert_eq!(args.y.unwrap().value(), "y");
+/// assert!(args.b);
+/// # syn::Result::Ok(())
+/// ```
+///
+/// # Parameter constraints
+///
+/// The following helper attributes restrict the combination of named parameters.
+///
+/// - `#[struct_meta(conflicts_with = "...")]` : the parameter cannot be used with the specified parameter.
+/// - `#[struct_meta(requires = "...")]` : the parameter cannot be used without the specified parameter.
+/// - `#[struct_meta(group = "...")]` : at most one of the parameters in the same group can be used.
+///
+/// By attaching `#[struct_meta(group(name = "...", required))]` to struct definition, exactly one of the parameters in the group must be used.
+///
+/// `conflicts_with` and `requires` can be specified multiple times.
+/// These constraints are checked after all arguments are parsed, and the error is reported at the name of the argument.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitStr, Result};
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(group(name = "mode", required))]
+/// struct Args {
+///     #[struct_meta(conflicts_with = "rename")]
+///     skip: bool,
+///     rename: Option<LitStr>,
+///     #[struct_meta(requires = "format")]
+///     with: Option<LitStr>,
+///     format: Option<LitStr>,
+///     #[struct_meta(group = "mode")]
+///     get: bool,
+///     #[struct_meta(group = "mode")]
+///     set: bool,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(get, skip)]);
+/// let args: Args = attr.parse_args()?;
+/// assert!(args.get && args.skip);
+///
+/// let attr: Attribute = parse_quote!(#[attr(get, skip, rename = "a")]);
+/// let args: Result<Args> = attr.parse_args();
+/// assert!(args.is_err());
+///
+/// let attr: Attribute = parse_quote!(#[attr(get, with = "a")]);
+/// let args: Result<Args> = attr.parse_args();
+/// assert!(args.is_err());
+///
+/// let attr: Attribute = parse_quote!(#[attr(get, set)]);
+/// let args: Result<Args> = attr.parse_args();
+/// assert!(args.is_err());
+///
+/// let attr: Attribute = parse_quote!(#[attr(skip)]);
+/// let args: Result<Args> = attr.parse_args();
+/// assert!(args.is_err());
+/// # syn::Result::Ok(())
+/// ```
+///
+/// # Enum
+///
+/// `#[derive(StructMeta)]` can also be specified for enum.
+///
+/// The variant is selected by the first argument, and the remaining arguments are parsed as the parameters of the variant.
+///
+/// The name of the variant is converted to snake_case. You can change it with `#[struct_meta(name = "...")]`.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitStr};
+///
+/// #[derive(StructMeta)]
+/// enum Route {
+///     Get {
+///         #[struct_meta(unnamed)]
+///         path: LitStr,
+///         auth: bool,
+///     },
+///     #[struct_meta(name = "ws")]
+///     WebSocket {
+///         #[struct_meta(unnamed)]
+///         path: LitStr,
+///         protocol: Option<LitStr>,
+///     },
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[route(get, "/x", auth)]);
+/// let route: Route = attr.parse_args()?;
+/// assert!(matches!(route, Route::Get { auth: true, .. }));
+///
+/// let attr: Attribute = parse_quote!(#[route(ws, "/y", protocol = "v2")]);
+/// let route: Route = attr.parse_args()?;
+/// assert!(matches!(route, Route::WebSocket { protocol: Some(_), .. }));
+/// # syn::Result::Ok(())
+/// ```
+///
+/// # Collecting errors
+///
+/// By default, parsing stops at the first error.
+///
+/// If you specify `#[struct_meta(collect_errors)]`, parsing continues from the next argument after an error,
+/// and all errors are reported together using [`syn::Error::combine`].
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitInt, LitStr, Result};
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(collect_errors)]
+/// struct Args {
+///     a: LitInt,
+///     b: LitStr,
+///     c: Option<LitInt>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(a = "x", x = 1, c = 1)]);
+/// let args: Result<Args> = attr.parse_args();
+/// let messages: Vec<_> = args.err().unwrap().into_iter().map(|e| e.to_string()).collect();
+/// assert_eq!(
+///     messages,
+///     vec![
+///         "expected integer literal",
+///         "cannot find parameter `x` in this scope",
+///         "missing argument `b = ...`",
+///     ]
+/// );
+/// ```
+///
+/// # Unknown arguments
+///
+/// By default, an argument with an unknown name is an error.
+///
+/// If you specify `#[struct_meta(help)]`, the error is followed by a second error listing the accepted parameters.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitStr, Result};
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(help)]
+/// struct Args {
+///     name: Option<LitStr>,
+///     skip: bool,
+///     with: Option<Vec<LitStr>>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(ignore)]);
+/// let args: Result<Args> = attr.parse_args();
+/// let messages: Vec<_> = args.err().unwrap().into_iter().map(|e| e.to_string()).collect();
+/// assert_eq!(
+///     messages,
+///     vec![
+///         "cannot find parameter `ignore` in this scope",
+///         "help: expected one of: `name = ...`, `skip`, `with(...)`",
+///     ]
+/// );
+/// ```
+///
+/// If you specify `#[struct_meta(allow_unknown)]`, arguments with unknown names are skipped.
+/// This is useful when multiple macros share one attribute.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitStr};
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(allow_unknown)]
+/// struct Args {
+///     rename: Option<LitStr>,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(skip, rename = "a", with(x))]);
+/// let args: Args = attr.parse_args()?;
+/// assert_eq!(args.rename.unwrap().value(), "a");
+/// # syn::Result::Ok(())
+/// ```
+///
+/// If a field of type [`UnknownArgs`] exists, arguments with unknown names are stored in that field as tokens.
+/// [`UnknownArgs`] implements [`quote::ToTokens`], so it can be forwarded to another macro.
+///
+/// ```rust
+/// use quote::quote;
+/// use structmeta::{StructMeta, UnknownArgs};
+/// use syn::{parse_quote, Attribute, LitStr};
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     rename: Option<LitStr>,
+///     others: UnknownArgs,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr(skip, rename = "a", with(x))]);
+/// let args: Args = attr.parse_args()?;
+/// let others = &args.others;
+/// assert_eq!(quote!(#[other(#others)]).to_string(), quote!(#[other(skip, with(x))]).to_string());
+/// # syn::Result::Ok(())
+/// ```
+///
+/// # Converting back to tokens
+///
+/// If you specify `#[struct_meta(to_tokens)]`, [`quote::ToTokens`] is also implemented.
+/// It emits the arguments in canonical form, so an attribute can be modified and emitted again.
+///
+/// - Unnamed parameters come first in order, followed by named parameters in field order.
+/// - Named parameters use the span of the original name when it is available.
+/// - Omitted optional parameters, `false` flags and empty repeated parameters are not emitted.
+/// - A field with `#[struct_meta(flatten)]` requires its type to implement [`quote::ToTokens`].
+///
+/// ```rust
+/// use quote::{quote, ToTokens};
+/// use structmeta::{Flag, StructMeta};
+/// use syn::{parse_quote, Attribute, LitInt, LitStr};
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(to_tokens)]
+/// struct Args {
+///     #[struct_meta(unnamed)]
+///     msg: LitStr,
+///     a: Option<LitInt>,
+///     b: Flag,
+/// }
+///
+/// let attr: Attribute = parse_quote!(#[attr("abc", b, a = 1)]);
+/// let mut args: Args = attr.parse_args()?;
+/// args.a = None;
+/// assert_eq!(args.to_token_stream().to_string(), quote!("abc", b).to_string());
+/// # syn::Result::Ok(())
+/// ```
+///
+/// # Inspecting parameters
+///
+/// `#[derive(StructMeta)]` also implements [`ArgsSchema`].
+/// [`ArgsSchema::SCHEMA`] lists the parameters with their names, aliases, styles, types and doc comments.
+///
+/// ```rust
+/// use structmeta::{ArgsSchema, StructMeta};
+/// use syn::LitStr;
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     /// The path of the route.
+///     path: LitStr,
+///     #[struct_meta(alias = "ignore")]
+///     skip: bool,
+/// }
+///
+/// for p in Args::SCHEMA.params {
+///     println!("{:?} {:?} : {}", p.name, p.style, p.doc);
+/// }
+/// ```
+///
 /// # Helper attribute `#[struct_meta(...)]`
 ///
-/// | argument                                           | struct | field | effect                                                                                   |
-/// | -------------------------------------------------- | ------ | ----- | ---------------------------------------------------------------------------------------- |
-/// | `dump`                                             | ✔      |       | Causes a compile error and outputs the automatically generated code as an error message. |
-/// | [`name_filter = "..."`](#struct_metaname_filter--) | ✔      |       | Specify how to distinguish between a parameter name and a value of an unnamed parameter. |
-/// | `name = "..."`                                     |        | ✔     | Specify a parameter name.                                                                |
-/// | `unnamed`                                          |        | ✔     | Make the field be treated as an unnamed parameter.                                       |
+/// | argument                                                  | struct | enum | variant | field | effect                                                                                   |
+/// | --------------------------------------------------------- | ------ | ---- | ------- | ----- | ---------------------------------------------------------------------------------------- |
+/// | `dump`                                                    | ✔      | ✔    |         |       | Causes a compile error and outputs the automatically generated code as an error message. |
+/// | [`name_filter = "..."`](#struct_metaname_filter--)        | ✔      | ✔    |         |       | Specify how to distinguish between a parameter name and a value of an unnamed parameter. |
+/// | [`rename_all = "..."`](#renaming-parameters)              | ✔      | ✔    |         |       | Convert field names to parameter names.                                                  |
+/// | [`group(name = "...", required)`](#parameter-constraints) | ✔      | ✔    |         |       | Declare a group whose parameters are mutually exclusive.                                 |
+/// | [`help`](#unknown-arguments)                              | ✔      | ✔    |         |       | List the accepted parameters in the error for an unknown argument.                       |
+/// | [`allow_unknown`](#unknown-arguments)                     | ✔      | ✔    |         |       | Skip arguments with unknown names.                                                       |
+/// | [`collect_errors`](#collecting-errors)                    | ✔      | ✔    |         |       | Report all errors instead of only the first one.                                         |
+/// | [`to_tokens`](#converting-back-to-tokens)                 | ✔      | ✔    |         |       | Also implement [`quote::ToTokens`].                                                      |
+/// | [`negatable`](#flag-style)                                | ✔      | ✔    |         | ✔     | Also accept `no_name` to turn off flags.                                                 |
+/// | [`name_value_separator = "..."`](#name-value-separator)   | ✔      | ✔    |         |       | Specify the separator of NameValue style arguments.                                      |
+/// | [`delimiters = "..."`](#delimiters)                       | ✔      | ✔    |         | ✔     | Specify the delimiters of NameArgs style arguments.                                      |
+/// | [`separator = "..."`](#separators)                        | ✔      | ✔    |         | ✔     | Specify the separator of arguments or list elements.                                     |
+/// | [`unnamed_anywhere`](#parameter-order)                    | ✔      | ✔    |         |       | Allow unnamed arguments after named arguments.                                           |
+/// | [`attr = "..."`](#finding-attributes)                     | ✔      | ✔    |         |       | Generate `from_attrs` and `from_attrs_required` for the attributes with the path.        |
+/// | `name = "..."`                                            |        |      | ✔       | ✔     | Specify a parameter name or a variant name.                                              |
+/// | [`alias = "..."`](#alias)                                 |        |      |         | ✔     | Specify another parameter name.                                                          |
+/// | `unnamed`                                                 |        |      |         | ✔     | Make the field be treated as an unnamed parameter.                                       |
+/// | [`default`](#default-value)                               |        |      |         | ✔     | Use [`Default::default()`] when the argument is omitted.                                 |
+/// | [`default = expr`](#default-value)                        |        |      |         | ✔     | Use `expr` when the argument is omitted.                                                 |
+/// | [`repeated`](#repeated-named-parameter)                   |        |      |         | ✔     | Allow the parameter to be specified multiple times.                                      |
+/// | [`conflicts_with = "..."`](#parameter-constraints)        |        |      |         | ✔     | Specify a parameter that cannot be used with the parameter.                              |
+/// | [`requires = "..."`](#parameter-constraints)              |        |      |         | ✔     | Specify a parameter that must be used with the parameter.                                |
+/// | [`group = "..."`](#parameter-constraints)                 |        |      |         | ✔     | Specify a group of mutually exclusive parameters.                                        |
+/// | [`rest`](#rest-named-parameter)                           |        |      |         | ✔     | Make the field be treated as a rest parameter.                                           |
+/// | [`flatten`](#flatten)                                     |        |      |         | ✔     | Merge the named parameters of the field type into the parameters.                        |
 ///
 /// # Uses with `#[proc_macro_derive]`
 ///
@@ -908,6 +1691,42 @@ pub use structmeta_derive::Parse;
 /// assert_eq!(MSG, "abc");
 /// ```
 ///
+/// Errors such as a missing argument are reported at the closing parenthesis of the attribute.
+/// Use [`parse_args_spanned`] instead of [`syn::Attribute::parse_args`] to report them at the path of the attribute.
+///
+/// ## Finding attributes
+///
+/// If you specify `#[struct_meta(attr = "...")]`, the following associated functions are generated.
+///
+/// - `from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Option<Self>>`
+/// - `from_attrs_required(attrs: &[syn::Attribute]) -> syn::Result<Self>`
+///
+/// They parse the arguments of all attributes with the specified path as one argument list, so the same parameter cannot be specified in two attributes.
+/// `from_attrs` returns `None` and `from_attrs_required` returns an error if there is no such attribute.
+///
+/// Multiple paths can be specified by separating them with `|`, and a path can contain `::`.
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, LitStr};
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(attr = "my_msg" | "my::msg")]
+/// struct MyAttr {
+///     msg: LitStr,
+///     skip: bool,
+/// }
+///
+/// let attrs: Vec<Attribute> = vec![
+///     parse_quote!(#[my_msg(msg = "abc")]),
+///     parse_quote!(#[my::msg(skip)]),
+/// ];
+/// let attr = MyAttr::from_attrs_required(&attrs)?;
+/// assert_eq!(attr.msg.value(), "abc");
+/// assert!(attr.skip);
+/// # syn::Result::Ok(())
+/// ```
+///
 /// # Uses with `#[proc_macro_attribute]`
 ///
 /// A type with `#[derive(StructMeta)]` can be used with `attr` parameter in attribute proc macro.
@@ -998,7 +1817,15 @@ pub use structmeta_derive::Parse;
 ///
 /// The following value can be used.
 ///
-/// - `"snake_case"`
+/// | value                    | names that can be used as parameter names           |
+/// | ------------------------ | --------------------------------------------------- |
+/// | `"snake_case"`           | lowercase letters, digits and `_`                   |
+/// | `"lowercase"`            | lowercase letters and digits                        |
+/// | `"UpperCamelCase"`       | letters and digits, beginning with uppercase letter |
+/// | `"SCREAMING_SNAKE_CASE"` | uppercase letters, digits and `_`                   |
+///
+/// You can also specify a path to a function of type `fn(&str) -> bool` instead of a string literal, such as `#[struct_meta(name_filter = my_filter)]`.
+/// The function returns `true` if the identifier can be used as a parameter name.
 ///
 /// ```rust
 /// use structmeta::StructMeta;
@@ -1034,5 +1861,26 @@ pub use structmeta_derive::Parse;
 /// assert_eq!(result.unnamed, Some(parse_quote!(X))); // `X` is parsed as a unnamed parameter.
 /// assert_eq!(result.x, false);
 /// ```
+///
+/// ```rust
+/// use structmeta::StructMeta;
+/// use syn::{parse_quote, Attribute, Ident};
+///
+/// fn not_underscore(s: &str) -> bool {
+///     !s.starts_with('_')
+/// }
+///
+/// #[derive(StructMeta)]
+/// #[struct_meta(name_filter = not_underscore)]
+/// struct Args {
+///     #[struct_meta(unnamed)]
+///     unnamed: Option<Ident>,
+///     x: bool,
+/// }
+/// let attr: Attribute = parse_quote!(#[attr(_x)]);
+/// let result: Args = attr.parse_args().unwrap();
+/// assert_eq!(result.unnamed.unwrap(), "_x"); // `_x` is parsed as a unnamed parameter.
+/// assert_eq!(result.x, false);
+/// ```
 // #[include_doc("../../doc/struct_meta.md", end)]
 pub use structmeta_derive::StructMeta;
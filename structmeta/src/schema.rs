@@ -0,0 +1,103 @@
+/// Type whose parameters can be inspected at runtime.
+///
+/// Implemented by [`#[derive(StructMeta)]`](macro@crate::StructMeta).
+///
+/// ```rust
+/// use structmeta::{ArgsSchema, Occurrence, ParamStyle, StructMeta};
+/// use syn::LitStr;
+///
+/// #[derive(StructMeta)]
+/// struct Args {
+///     /// The path of the route.
+///     path: LitStr,
+///     #[struct_meta(alias = "ignore")]
+///     skip: bool,
+/// }
+///
+/// let params = Args::SCHEMA.params;
+/// assert_eq!(params[0].name, Some("path"));
+/// assert_eq!(params[0].style, ParamStyle::NameValue);
+/// assert_eq!(params[0].occurrence, Occurrence::Required);
+/// assert_eq!(params[0].ty, "LitStr");
+/// assert_eq!(params[0].doc, "The path of the route.");
+/// assert_eq!(params[1].aliases, ["ignore"]);
+/// assert_eq!(params[1].style, ParamStyle::Flag);
+/// ```
+pub trait ArgsSchema {
+    const SCHEMA: &'static Schema;
+}
+
+/// Parameters of a struct, or variants of an enum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Schema {
+    /// Parameters in the order of the fields.
+    pub params: &'static [ParamSchema],
+    /// Schemas of the fields with `#[struct_meta(flatten)]`.
+    pub flatten: &'static [&'static Schema],
+    /// Rest parameter, which accepts arguments with any name.
+    pub rest: Option<&'static ParamSchema>,
+    /// Whether arguments with unknown names are allowed.
+    pub allow_unknown: bool,
+    /// Variants of an enum. Empty for a struct.
+    pub variants: &'static [VariantSchema],
+}
+
+/// Variant of an enum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VariantSchema {
+    pub name: &'static str,
+    pub doc: &'static str,
+    pub schema: Schema,
+}
+
+/// Parameter of a struct or an enum variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParamSchema {
+    /// Parameter name. `None` for unnamed and rest parameters.
+    pub name: Option<&'static str>,
+    pub aliases: &'static [&'static str],
+    /// Name of the negated flag specified by `#[struct_meta(negatable)]`.
+    pub negated_name: Option<&'static str>,
+    pub style: ParamStyle,
+    pub occurrence: Occurrence,
+    /// Field type as written in the source code.
+    pub ty: &'static str,
+    /// Doc comment of the field.
+    pub doc: &'static str,
+}
+
+/// Style of a parameter.
+///
+/// See [`#[derive(StructMeta)]`](macro@crate::StructMeta) documentation for details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamStyle {
+    /// `value`
+    Unnamed,
+    /// `name`
+    Flag,
+    /// `name = value`
+    NameValue,
+    /// `name = value` or `name`
+    NameValueOrFlag,
+    /// `name(args)`
+    NameArgs,
+    /// `name(args)` or `name`
+    NameArgsOrFlag,
+    /// `name(arg, arg, ...)`
+    NameArgList,
+    /// `name(arg, arg, ...)` or `name`
+    NameArgListOrFlag,
+}
+
+/// How many times a parameter can be specified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Occurrence {
+    /// Exactly once.
+    Required,
+    /// At most once.
+    Optional,
+    /// Any number of unnamed arguments.
+    Variadic,
+    /// Any number of times.
+    Repeated,
+}